@@ -1,8 +1,17 @@
 use super::plugins::aiming_plugin::AimingPlugin;
+use super::plugins::breakable_plugin::BreakablePlugin;
 use super::plugins::custom_tweening_plugin::CustomTweeningPlugin;
+use super::plugins::force_field_plugin::ForceFieldPlugin;
 use super::plugins::game_camera_plugin::{GameCamera, GameCameraPlugin};
 use super::plugins::game_scene_plugin::{GameData, GameScenePlugin, SetGameLevel};
 use super::plugins::game_ui_plugin::GameUiPlugin;
+use super::plugins::joint_plugin::JointPlugin;
+use super::plugins::pad_plugin::PadPlugin;
+use super::plugins::portal_plugin::PortalPlugin;
+use super::plugins::power_up_plugin::PowerUpPlugin;
+use super::plugins::replay_plugin::ReplayPlugin;
+use super::plugins::rewind_plugin::RewindPlugin;
+use super::plugins::switch_plugin::SwitchPlugin;
 use crate::AppState;
 use bevy::ecs::schedule::ScheduleLabel;
 use bevy::prelude::*;
@@ -19,6 +28,11 @@ pub enum GameState {
 
 pub struct GamePlugin;
 
+pub const PHYSICS_TIMESTEP: f32 = 1.0 / 60.0;
+pub const PHYSICS_SUBSTEPS: usize = 4;
+/// Time step of the physics substeps, over which contact forces are reported.
+pub const PHYSICS_SUBSTEP_DT: f32 = PHYSICS_TIMESTEP / PHYSICS_SUBSTEPS as f32;
+
 #[derive(ScheduleLabel, Hash, PartialEq, Eq, Debug, Clone)]
 pub struct PhysicsSchedule;
 
@@ -26,7 +40,7 @@ impl Plugin for GamePlugin {
     fn build(&self, app: &mut App) {
         app.init_schedule(PhysicsSchedule)
             .init_state::<GameState>()
-            .insert_resource(Time::<Fixed>::from_hz(60.0))
+            .insert_resource(Time::<Fixed>::from_seconds(PHYSICS_TIMESTEP as f64))
             .add_systems(
                 FixedUpdate,
                 run_physics_schedule.run_if(in_state(GameState::Playing)),
@@ -40,6 +54,15 @@ impl Plugin for GamePlugin {
                 GameScenePlugin,
                 CustomTweeningPlugin,
                 GameUiPlugin,
+                PowerUpPlugin,
+                PadPlugin,
+                PortalPlugin,
+                ForceFieldPlugin,
+                SwitchPlugin,
+                BreakablePlugin,
+                JointPlugin,
+                RewindPlugin,
+                ReplayPlugin,
             ))
             .add_systems(OnEnter(AppState::InGame), start_game)
             .add_systems(OnExit(AppState::InGame), cleanup_game);
@@ -61,8 +84,8 @@ fn start_game(
     // collision config
     rapier_config.gravity = Vec3::Z * -9.81;
     rapier_config.timestep_mode = TimestepMode::Fixed {
-        dt: 1.0 / 60.0,
-        substeps: 4,
+        dt: PHYSICS_TIMESTEP,
+        substeps: PHYSICS_SUBSTEPS,
     };
 
     // light
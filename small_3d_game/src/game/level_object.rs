@@ -0,0 +1,844 @@
+use super::plugins::{
+    breakable_plugin::GameBreakable,
+    force_field_plugin::{ForceFieldMode, GameForceField, GameWater},
+    game_scene_plugin::{
+        GameDoor, GameDynamic, GameGoal, GameHazard, GameKey, GamePoints, KeyColor,
+    },
+    joint_plugin::{GameJoint, JointKind},
+    pad_plugin::{GameBooster, GameBouncer, GameConveyor},
+    portal_plugin::{GamePortal, PortalSide},
+    power_up_plugin::{GamePowerUp, PowerUpKind},
+    switch_plugin::{GameSwitch, SwitchAction},
+};
+use crate::log;
+use bevy::prelude::*;
+use serde_derive::Deserialize;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize)]
+pub enum LevelObjectKind {
+    #[default]
+    Object,
+    Bounds,
+    Spawn,
+    Goal,
+    Point,
+    Hazard,
+    PowerUp,
+    Booster,
+    Bouncer,
+    Portal,
+    ForceField,
+    Key,
+    Door,
+    Switch,
+    Breakable,
+    Dynamic,
+    Checkpoint,
+    Water,
+    Conveyor,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+pub enum GoalAxis {
+    #[serde(rename = "X+")]
+    PositiveX,
+    #[serde(rename = "X-")]
+    NegativeX,
+    #[serde(rename = "Y+")]
+    PositiveY,
+    #[serde(rename = "Y-")]
+    NegativeY,
+    #[serde(rename = "Z+")]
+    PositiveZ,
+    #[serde(rename = "Z-")]
+    NegativeZ,
+}
+
+impl GoalAxis {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "X+" => Some(Self::PositiveX),
+            "X-" => Some(Self::NegativeX),
+            "Y+" => Some(Self::PositiveY),
+            "Y-" => Some(Self::NegativeY),
+            "Z+" => Some(Self::PositiveZ),
+            "Z-" => Some(Self::NegativeZ),
+            _ => None,
+        }
+    }
+
+    pub fn to_vec3(self) -> Vec3 {
+        match self {
+            Self::PositiveX => Vec3::X,
+            Self::NegativeX => -Vec3::X,
+            Self::PositiveY => Vec3::Y,
+            Self::NegativeY => -Vec3::Y,
+            Self::PositiveZ => Vec3::Z,
+            Self::NegativeZ => -Vec3::Z,
+        }
+    }
+}
+
+/// World space pass-through direction of a goal, either an axis name like
+/// `"Z-"` or a vector like `[1, 1, 0]`.
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize)]
+#[serde(untagged)]
+pub enum GoalDirection {
+    Axis(GoalAxis),
+    Vector([f32; 3]),
+}
+
+impl GoalDirection {
+    /// Returns `None` for a zero vector.
+    pub fn to_vec3(self) -> Option<Vec3> {
+        match self {
+            Self::Axis(axis) => Some(axis.to_vec3()),
+            Self::Vector(v) => Vec3::from(v).try_normalize(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+pub enum HazardMode {
+    Loss,
+    Penalty,
+    Respawn,
+}
+
+/// Level object parameters, read from the node custom properties (glTF extras)
+/// or parsed from the node name, e.g. `Goal_X+` or `Point_10.001`.
+/// A goal without a `direction` is passed through along its local Y axis.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize)]
+pub struct LevelObjectDescriptor {
+    pub kind: LevelObjectKind,
+    pub reward: Option<i32>,
+    pub direction: Option<GoalDirection>,
+    pub hazard: Option<HazardMode>,
+    pub penalty: Option<i32>,
+    pub power_up: Option<PowerUpKind>,
+    pub duration: Option<f32>,
+    pub strength: Option<f32>,
+    pub portal: Option<u32>,
+    pub side: Option<PortalSide>,
+    pub field: Option<ForceFieldMode>,
+    pub color: Option<KeyColor>,
+    /// Name of the animated object controlled by a switch.
+    pub target: Option<String>,
+    pub action: Option<SwitchAction>,
+    /// Keeps the object animation paused until a switch starts it.
+    pub paused: Option<bool>,
+    pub threshold: Option<f32>,
+    /// Path of the glb scene replacing a broken object.
+    pub debris: Option<String>,
+    pub mass: Option<f32>,
+    /// Joint attaching a dynamic object to its `target`.
+    pub joint: Option<JointKind>,
+    pub axis: Option<[f32; 3]>,
+    pub limits: Option<[f32; 2]>,
+    pub motor: Option<f32>,
+    pub buoyancy: Option<f32>,
+    pub damping: Option<f32>,
+    pub speed: Option<f32>,
+    /// Surface type from `surfaces.json`, overridden by `friction` and `restitution`.
+    pub surface: Option<String>,
+    pub friction: Option<f32>,
+    pub restitution: Option<f32>,
+}
+
+impl LevelObjectDescriptor {
+    pub fn parse(name: &str, extras: Option<&str>) -> Option<Self> {
+        extras
+            .and_then(|extras| match Self::from_extras(extras) {
+                Ok(descriptor) => descriptor,
+                Err(e) => {
+                    log!("{}: invalid level object extras: {}", name, e);
+                    None
+                }
+            })
+            .or_else(|| Self::from_name(name))
+    }
+
+    /// Extras without a `kind` do not describe a level object.
+    pub fn from_extras(extras: &str) -> Result<Option<Self>, serde_json::Error> {
+        let value = serde_json::from_str::<serde_json::Value>(extras)?;
+        if value.get("kind").is_none() {
+            return Ok(None);
+        }
+        serde_json::from_value(value).map(Some)
+    }
+
+    /// Names are read as they are before stripping the `.001` suffix of
+    /// duplicated names, which keeps suffixed switch targets and three decimal
+    /// numbers, e.g. `Wind_0.125`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::from_exact_name(name).or_else(|| Self::from_exact_name(strip_duplicate_suffix(name)))
+    }
+
+    fn from_exact_name(name: &str) -> Option<Self> {
+        let param = name.split_once('_').map(|(_, p)| p).unwrap_or_default();
+        let descriptor = match name {
+            s if s.starts_with("Object") => Self::default(),
+            "Bounds" => Self {
+                kind: LevelObjectKind::Bounds,
+                ..Default::default()
+            },
+            "Spawn" => Self {
+                kind: LevelObjectKind::Spawn,
+                ..Default::default()
+            },
+            s if s.starts_with("Checkpoint") => Self {
+                kind: LevelObjectKind::Checkpoint,
+                ..Default::default()
+            },
+            s if s.starts_with("Goal") => Self {
+                kind: LevelObjectKind::Goal,
+                direction: match param {
+                    "" => None,
+                    p => Some(GoalDirection::Axis(GoalAxis::from_name(p.get(..2)?)?)),
+                },
+                ..Default::default()
+            },
+            s if s.starts_with("Point") => Self {
+                kind: LevelObjectKind::Point,
+                reward: Some(param.parse().ok()?),
+                ..Default::default()
+            },
+            s if s.starts_with("Hazard") => {
+                let (hazard, penalty) = match param {
+                    "Loss" => (HazardMode::Loss, None),
+                    "Respawn" => (HazardMode::Respawn, None),
+                    p => (
+                        HazardMode::Penalty,
+                        Some(p.strip_prefix("Penalty")?.parse().ok()?),
+                    ),
+                };
+                Self {
+                    kind: LevelObjectKind::Hazard,
+                    hazard: Some(hazard),
+                    penalty,
+                    ..Default::default()
+                }
+            }
+            s if s.starts_with("PowerUp") => {
+                let (power_up, duration) = param.split_at(
+                    param
+                        .find(|c: char| c.is_ascii_digit())
+                        .unwrap_or(param.len()),
+                );
+                let power_up = match power_up {
+                    "Shot" => PowerUpKind::ExtraShot,
+                    "Double" => PowerUpKind::DoublePoints,
+                    "LowGravity" => PowerUpKind::LowGravity,
+                    "Sticky" => PowerUpKind::Sticky,
+                    _ => return None,
+                };
+                let duration = Self::parse_optional(duration)?;
+                Self {
+                    kind: LevelObjectKind::PowerUp,
+                    power_up: Some(power_up),
+                    duration,
+                    ..Default::default()
+                }
+            }
+            s if s.starts_with("Booster") => Self {
+                kind: LevelObjectKind::Booster,
+                strength: Self::parse_optional(param)?,
+                ..Default::default()
+            },
+            s if s.starts_with("Bouncer") => Self {
+                kind: LevelObjectKind::Bouncer,
+                strength: Self::parse_optional(param)?,
+                ..Default::default()
+            },
+            s if s.starts_with("Wind") => Self::force_field(ForceFieldMode::Wind, param, 1.0)?,
+            s if s.starts_with("Attractor") => {
+                Self::force_field(ForceFieldMode::Radial, param, 1.0)?
+            }
+            s if s.starts_with("Repeller") => {
+                Self::force_field(ForceFieldMode::Radial, param, -1.0)?
+            }
+            s if s.starts_with("Gravity") => {
+                Self::force_field(ForceFieldMode::Gravity, param, 1.0)?
+            }
+            s if s.starts_with("Key") => Self {
+                kind: LevelObjectKind::Key,
+                color: Some(KeyColor::from_name(param)?),
+                ..Default::default()
+            },
+            s if s.starts_with("Door") => Self {
+                kind: LevelObjectKind::Door,
+                color: Some(KeyColor::from_name(param)?),
+                ..Default::default()
+            },
+            s if s.starts_with("Switch") => Self {
+                kind: LevelObjectKind::Switch,
+                target: Some(param.to_string()).filter(|t| !t.is_empty()),
+                ..Default::default()
+            },
+            s if s.starts_with("Breakable") => Self {
+                kind: LevelObjectKind::Breakable,
+                threshold: Self::parse_optional(param)?,
+                ..Default::default()
+            },
+            s if s.starts_with("Dynamic") => Self {
+                kind: LevelObjectKind::Dynamic,
+                mass: Self::parse_optional(param)?,
+                ..Default::default()
+            },
+            s if s.starts_with("Water") => Self {
+                kind: LevelObjectKind::Water,
+                buoyancy: Self::parse_optional(param)?,
+                ..Default::default()
+            },
+            s if s.starts_with("Conveyor") => Self {
+                kind: LevelObjectKind::Conveyor,
+                speed: Self::parse_optional(param)?,
+                ..Default::default()
+            },
+            s if s.starts_with("Portal") => {
+                let (portal, side) = param.split_once('_')?;
+                let side = match side {
+                    "A" => PortalSide::A,
+                    "B" => PortalSide::B,
+                    _ => return None,
+                };
+                Self {
+                    kind: LevelObjectKind::Portal,
+                    portal: Some(portal.parse().ok()?),
+                    side: Some(side),
+                    ..Default::default()
+                }
+            }
+            _ => return None,
+        };
+
+        Some(descriptor)
+    }
+
+    fn force_field(field: ForceFieldMode, param: &str, sign: f32) -> Option<Self> {
+        let strength = match param {
+            "" => match field {
+                ForceFieldMode::Radial => Some(GameForceField::DEFAULT_RADIAL * sign),
+                _ => None,
+            },
+            p => Some(p.parse::<f32>().ok()? * sign),
+        };
+        Some(Self {
+            kind: LevelObjectKind::ForceField,
+            field: Some(field),
+            strength,
+            ..Default::default()
+        })
+    }
+
+    /// Empty parameters parse to `Some(None)`, invalid ones to `None`.
+    fn parse_optional<T: std::str::FromStr>(param: &str) -> Option<Option<T>> {
+        match param {
+            "" => Some(None),
+            p => p.parse().ok().map(Some),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum GameLevelObjectType {
+    Object,
+    Bounds,
+    Spawn,
+    Checkpoint,
+    Goal(GameGoal),
+    Point(GamePoints),
+    Hazard(GameHazard),
+    PowerUp(GamePowerUp),
+    Booster(GameBooster),
+    Bouncer(GameBouncer),
+    Portal(GamePortal),
+    ForceField(GameForceField),
+    Key(GameKey),
+    Door(GameDoor),
+    Switch(GameSwitch),
+    Breakable(GameBreakable),
+    Dynamic(GameDynamic, Option<GameJoint>),
+    Water(GameWater),
+    Conveyor(GameConveyor),
+}
+
+impl TryFrom<&LevelObjectDescriptor> for GameLevelObjectType {
+    type Error = String;
+
+    fn try_from(value: &LevelObjectDescriptor) -> Result<Self, String> {
+        fn required<T>(field: Option<T>, name: &str) -> Result<T, String> {
+            field.ok_or_else(|| format!("missing `{}`", name))
+        }
+
+        Ok(match value.kind {
+            LevelObjectKind::Object => Self::Object,
+            LevelObjectKind::Bounds => Self::Bounds,
+            LevelObjectKind::Spawn => Self::Spawn,
+            LevelObjectKind::Checkpoint => Self::Checkpoint,
+            LevelObjectKind::Goal => Self::Goal(match value.direction {
+                Some(direction) => GameGoal::World(
+                    direction
+                        .to_vec3()
+                        .ok_or_else(|| "zero goal `direction`".to_string())?,
+                ),
+                None => GameGoal::Local(Vec3::Y),
+            }),
+            LevelObjectKind::Point => Self::Point(GamePoints {
+                reward: required(value.reward, "reward")?,
+            }),
+            LevelObjectKind::Hazard => Self::Hazard(match required(value.hazard, "hazard")? {
+                HazardMode::Loss => GameHazard::Loss,
+                HazardMode::Penalty => GameHazard::Penalty(required(value.penalty, "penalty")?),
+                HazardMode::Respawn => GameHazard::Respawn,
+            }),
+            LevelObjectKind::PowerUp => Self::PowerUp(GamePowerUp {
+                kind: required(value.power_up, "power_up")?,
+                duration: value.duration.unwrap_or(GamePowerUp::DEFAULT_DURATION),
+            }),
+            LevelObjectKind::Booster => Self::Booster(GameBooster {
+                strength: value.strength.unwrap_or(GameBooster::DEFAULT_STRENGTH),
+            }),
+            LevelObjectKind::Bouncer => Self::Bouncer(GameBouncer {
+                strength: value.strength.unwrap_or(GameBouncer::DEFAULT_STRENGTH),
+            }),
+            LevelObjectKind::ForceField => Self::ForceField(GameForceField::new(
+                required(value.field, "field")?,
+                value.strength,
+            )),
+            LevelObjectKind::Key => Self::Key(GameKey {
+                color: required(value.color, "color")?,
+            }),
+            LevelObjectKind::Door => Self::Door(GameDoor {
+                color: required(value.color, "color")?,
+            }),
+            LevelObjectKind::Switch => Self::Switch(GameSwitch {
+                target: required(value.target.clone(), "target")?,
+                action: value.action.unwrap_or_default(),
+            }),
+            LevelObjectKind::Breakable => Self::Breakable(GameBreakable {
+                threshold: value.threshold.unwrap_or(GameBreakable::DEFAULT_THRESHOLD),
+                reward: value.reward.unwrap_or_default(),
+            }),
+            LevelObjectKind::Dynamic => Self::Dynamic(
+                GameDynamic {
+                    mass: value.mass.unwrap_or(GameDynamic::DEFAULT_MASS),
+                },
+                value.joint.map(|kind| GameJoint {
+                    kind,
+                    target: value.target.clone(),
+                    axis: value.axis.map(Vec3::from).unwrap_or(Vec3::Z),
+                    limits: value.limits,
+                    motor: value.motor,
+                }),
+            ),
+            LevelObjectKind::Water => Self::Water(GameWater {
+                buoyancy: value.buoyancy.unwrap_or(GameWater::DEFAULT_BUOYANCY),
+                damping: value.damping.unwrap_or(GameWater::DEFAULT_DAMPING),
+            }),
+            LevelObjectKind::Conveyor => Self::Conveyor(GameConveyor {
+                speed: value.speed.unwrap_or(GameConveyor::DEFAULT_SPEED),
+            }),
+            LevelObjectKind::Portal => Self::Portal(GamePortal {
+                id: required(value.portal, "portal")?,
+                side: required(value.side, "side")?,
+            }),
+        })
+    }
+}
+
+/// Name without the `.001` suffix Blender adds to duplicated names.
+pub fn strip_duplicate_suffix(name: &str) -> &str {
+    match name.rsplit_once('.') {
+        Some((base, suffix)) if suffix.len() == 3 && suffix.bytes().all(|b| b.is_ascii_digit()) => {
+            base
+        }
+        _ => name,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(name: &str, extras: Option<&str>) -> Option<GameLevelObjectType> {
+        LevelObjectDescriptor::parse(name, extras)
+            .and_then(|descriptor| GameLevelObjectType::try_from(&descriptor).ok())
+    }
+
+    #[test]
+    fn test_strip_duplicate_suffix() {
+        assert_eq!(strip_duplicate_suffix("Point_10.001"), "Point_10");
+        assert_eq!(strip_duplicate_suffix("Booster_1.5"), "Booster_1.5");
+        assert_eq!(strip_duplicate_suffix("Booster_1.5.002"), "Booster_1.5");
+        assert_eq!(strip_duplicate_suffix("Cube.01"), "Cube.01");
+        assert_eq!(strip_duplicate_suffix("Cube.abc"), "Cube.abc");
+    }
+
+    #[test]
+    fn test_parse_unknown() {
+        assert_eq!(parse("Cube.001", None), None);
+        assert_eq!(parse("Cube", Some(r#"{"color": "red"}"#)), None);
+    }
+
+    #[test]
+    fn test_parse_plain_objects() {
+        assert_eq!(parse("Object", None), Some(GameLevelObjectType::Object));
+        assert_eq!(
+            parse("Object_Wall.002", None),
+            Some(GameLevelObjectType::Object)
+        );
+        assert_eq!(parse("Bounds", None), Some(GameLevelObjectType::Bounds));
+        assert_eq!(parse("Spawn", None), Some(GameLevelObjectType::Spawn));
+        assert_eq!(
+            parse("Checkpoint_Bridge.001", None),
+            Some(GameLevelObjectType::Checkpoint)
+        );
+    }
+
+    #[test]
+    fn test_parse_goal() {
+        assert_eq!(
+            parse("Goal_X-.001", None),
+            Some(GameLevelObjectType::Goal(GameGoal::World(-Vec3::X)))
+        );
+        assert_eq!(
+            parse("Goal_Z-", None),
+            Some(GameLevelObjectType::Goal(GameGoal::World(-Vec3::Z)))
+        );
+        assert_eq!(
+            parse("Goal.002", None),
+            Some(GameLevelObjectType::Goal(GameGoal::Local(Vec3::Y)))
+        );
+        assert_eq!(parse("Goal_W+", None), None);
+
+        assert_eq!(
+            parse("Cube.001", Some(r#"{"kind": "Goal", "direction": "Y+"}"#)),
+            Some(GameLevelObjectType::Goal(GameGoal::World(Vec3::Y)))
+        );
+        assert_eq!(
+            parse(
+                "Cube.001",
+                Some(r#"{"kind": "Goal", "direction": [2, 0, 2]}"#)
+            ),
+            Some(GameLevelObjectType::Goal(GameGoal::World(
+                Vec3::new(1.0, 0.0, 1.0).normalize()
+            )))
+        );
+        assert_eq!(
+            parse(
+                "Cube.001",
+                Some(r#"{"kind": "Goal", "direction": [0, 0, 0]}"#)
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_parse_point() {
+        assert_eq!(
+            parse("Point_10.001", None),
+            Some(GameLevelObjectType::Point(GamePoints { reward: 10 }))
+        );
+        assert_eq!(parse("Point_ten", None), None);
+
+        assert_eq!(
+            parse("Cube.001", Some(r#"{"kind": "Point", "reward": 25}"#)),
+            Some(GameLevelObjectType::Point(GamePoints { reward: 25 }))
+        );
+        assert_eq!(parse("Cube", Some(r#"{"kind": "Point"}"#)), None);
+    }
+
+    #[test]
+    fn test_parse_extras_precedence() {
+        // extras take precedence over the name
+        assert_eq!(
+            parse("Point_10", Some(r#"{"kind": "Point", "reward": 20}"#)),
+            Some(GameLevelObjectType::Point(GamePoints { reward: 20 }))
+        );
+        // extras without a kind or invalid extras fall back to the name
+        assert_eq!(
+            parse("Point_10", Some(r#"{"color": "red"}"#)),
+            Some(GameLevelObjectType::Point(GamePoints { reward: 10 }))
+        );
+        assert_eq!(
+            parse("Point_10", Some(r#"{"kind": "Rock"}"#)),
+            Some(GameLevelObjectType::Point(GamePoints { reward: 10 }))
+        );
+    }
+
+    #[test]
+    fn test_parse_hazard() {
+        assert_eq!(
+            parse("Hazard_Penalty15", None),
+            Some(GameLevelObjectType::Hazard(GameHazard::Penalty(15)))
+        );
+        assert_eq!(
+            parse("Hazard_Respawn.003", None),
+            Some(GameLevelObjectType::Hazard(GameHazard::Respawn))
+        );
+        assert_eq!(parse("Hazard_Penalty", None), None);
+        assert_eq!(
+            parse("Cube", Some(r#"{"kind": "Hazard", "hazard": "Penalty"}"#)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_parse_power_up() {
+        assert_eq!(
+            parse("PowerUp_Double5", None),
+            Some(GameLevelObjectType::PowerUp(GamePowerUp {
+                kind: PowerUpKind::DoublePoints,
+                duration: 5.0,
+            }))
+        );
+        assert_eq!(
+            parse("PowerUp_Shot", None),
+            Some(GameLevelObjectType::PowerUp(GamePowerUp {
+                kind: PowerUpKind::ExtraShot,
+                duration: GamePowerUp::DEFAULT_DURATION,
+            }))
+        );
+        assert_eq!(parse("PowerUp_Magnet", None), None);
+
+        assert_eq!(
+            parse(
+                "Cube.001",
+                Some(r#"{"kind": "PowerUp", "power_up": "LowGravity", "duration": 3}"#)
+            ),
+            Some(GameLevelObjectType::PowerUp(GamePowerUp {
+                kind: PowerUpKind::LowGravity,
+                duration: 3.0,
+            }))
+        );
+    }
+
+    #[test]
+    fn test_parse_surface() {
+        let descriptor = LevelObjectDescriptor::parse(
+            "Object",
+            Some(r#"{"kind": "Object", "friction": 0.1, "restitution": 0.9}"#),
+        )
+        .unwrap();
+        assert_eq!(descriptor.friction, Some(0.1));
+        assert_eq!(descriptor.restitution, Some(0.9));
+        let descriptor =
+            LevelObjectDescriptor::parse("Object", Some(r#"{"kind": "Object", "surface": "ice"}"#))
+                .unwrap();
+        assert_eq!(descriptor.surface.as_deref(), Some("ice"));
+    }
+
+    #[test]
+    fn test_parse_pads() {
+        assert_eq!(
+            parse("Booster_150", None),
+            Some(GameLevelObjectType::Booster(GameBooster {
+                strength: 150.0
+            }))
+        );
+        assert_eq!(
+            parse("Booster_1.5.001", None),
+            Some(GameLevelObjectType::Booster(GameBooster { strength: 1.5 }))
+        );
+        assert_eq!(parse("Booster_fast", None), None);
+        assert_eq!(
+            parse("Bouncer.001", None),
+            Some(GameLevelObjectType::Bouncer(GameBouncer {
+                strength: GameBouncer::DEFAULT_STRENGTH,
+            }))
+        );
+    }
+
+    #[test]
+    fn test_parse_portal() {
+        assert_eq!(
+            parse("Portal_2_B.001", None),
+            Some(GameLevelObjectType::Portal(GamePortal {
+                id: 2,
+                side: PortalSide::B,
+            }))
+        );
+        assert_eq!(parse("Portal_2", None), None);
+        assert_eq!(parse("Portal_2_C", None), None);
+
+        assert_eq!(
+            parse(
+                "Cube.001",
+                Some(r#"{"kind": "Portal", "portal": 7, "side": "A"}"#)
+            ),
+            Some(GameLevelObjectType::Portal(GamePortal {
+                id: 7,
+                side: PortalSide::A,
+            }))
+        );
+    }
+
+    #[test]
+    fn test_parse_force_field() {
+        assert_eq!(
+            parse("Wind_3", None),
+            Some(GameLevelObjectType::ForceField(GameForceField::Wind(3.0)))
+        );
+        assert_eq!(
+            parse("Wind_0.5", None),
+            Some(GameLevelObjectType::ForceField(GameForceField::Wind(0.5)))
+        );
+        assert_eq!(
+            parse("Wind_0.125", None),
+            Some(GameLevelObjectType::ForceField(GameForceField::Wind(0.125)))
+        );
+        assert_eq!(
+            parse("Repeller.002", None),
+            Some(GameLevelObjectType::ForceField(GameForceField::Radial(
+                -GameForceField::DEFAULT_RADIAL
+            )))
+        );
+        assert_eq!(
+            parse("Gravity", None),
+            Some(GameLevelObjectType::ForceField(GameForceField::Gravity(
+                GameForceField::DEFAULT_GRAVITY
+            )))
+        );
+    }
+
+    #[test]
+    fn test_parse_key_and_door() {
+        assert_eq!(
+            parse("Key_Red", None),
+            Some(GameLevelObjectType::Key(GameKey {
+                color: KeyColor::Red
+            }))
+        );
+        assert_eq!(
+            parse("Door_Red.001", None),
+            Some(GameLevelObjectType::Door(GameDoor {
+                color: KeyColor::Red
+            }))
+        );
+        assert_eq!(parse("Door_Purple", None), None);
+    }
+
+    #[test]
+    fn test_parse_switch() {
+        assert_eq!(
+            parse("Switch_Platform", None),
+            Some(GameLevelObjectType::Switch(GameSwitch {
+                target: "Platform".to_string(),
+                action: SwitchAction::Toggle,
+            }))
+        );
+        assert_eq!(
+            parse("Switch_Object.028", None),
+            Some(GameLevelObjectType::Switch(GameSwitch {
+                target: "Object.028".to_string(),
+                action: SwitchAction::Toggle,
+            }))
+        );
+        assert_eq!(parse("Switch", None), None);
+
+        assert_eq!(
+            parse(
+                "Cube.001",
+                Some(r#"{"kind": "Switch", "target": "Object_Gate.001", "action": "Reverse"}"#)
+            ),
+            Some(GameLevelObjectType::Switch(GameSwitch {
+                target: "Object_Gate.001".to_string(),
+                action: SwitchAction::Reverse,
+            }))
+        );
+    }
+
+    #[test]
+    fn test_parse_breakable() {
+        assert_eq!(
+            parse("Breakable_120", None),
+            Some(GameLevelObjectType::Breakable(GameBreakable {
+                threshold: 120.0,
+                reward: 0,
+            }))
+        );
+
+        let descriptor = LevelObjectDescriptor::parse(
+            "Crate",
+            Some(r#"{"kind": "Breakable", "reward": 5, "debris": "models/debris/crate.glb"}"#),
+        )
+        .unwrap();
+        assert_eq!(
+            descriptor.debris.as_deref(),
+            Some("models/debris/crate.glb")
+        );
+        assert_eq!(
+            GameLevelObjectType::try_from(&descriptor),
+            Ok(GameLevelObjectType::Breakable(GameBreakable {
+                threshold: GameBreakable::DEFAULT_THRESHOLD,
+                reward: 5,
+            }))
+        );
+    }
+
+    #[test]
+    fn test_parse_dynamic() {
+        assert_eq!(
+            parse("Dynamic_20.5.001", None),
+            Some(GameLevelObjectType::Dynamic(
+                GameDynamic { mass: 20.5 },
+                None
+            ))
+        );
+
+        assert_eq!(
+            parse(
+                "Seesaw",
+                Some(
+                    r#"{
+                        "kind": "Dynamic",
+                        "joint": "Revolute",
+                        "target": "Object_Base",
+                        "axis": [1, 0, 0],
+                        "limits": [-0.5, 0.5]
+                    }"#
+                )
+            ),
+            Some(GameLevelObjectType::Dynamic(
+                GameDynamic {
+                    mass: GameDynamic::DEFAULT_MASS,
+                },
+                Some(GameJoint {
+                    kind: JointKind::Revolute,
+                    target: Some("Object_Base".to_string()),
+                    axis: Vec3::X,
+                    limits: Some([-0.5, 0.5]),
+                    motor: None,
+                })
+            ))
+        );
+    }
+
+    #[test]
+    fn test_parse_water() {
+        assert_eq!(
+            parse("Water_2", None),
+            Some(GameLevelObjectType::Water(GameWater {
+                buoyancy: 2.0,
+                damping: GameWater::DEFAULT_DAMPING,
+            }))
+        );
+    }
+
+    #[test]
+    fn test_parse_conveyor() {
+        assert_eq!(
+            parse("Conveyor_-3", None),
+            Some(GameLevelObjectType::Conveyor(GameConveyor { speed: -3.0 }))
+        );
+        assert_eq!(
+            parse("Conveyor_2.5", None),
+            Some(GameLevelObjectType::Conveyor(GameConveyor { speed: 2.5 }))
+        );
+        assert_eq!(
+            parse("Conveyor_1.250", None),
+            Some(GameLevelObjectType::Conveyor(GameConveyor { speed: 1.25 }))
+        );
+    }
+}
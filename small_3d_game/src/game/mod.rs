@@ -1,3 +1,4 @@
 pub mod game_plugin;
 
+pub(super) mod level_object;
 pub(super) mod plugins;
@@ -1,11 +1,13 @@
 use super::{
     custom_tweening_plugin::{update_scale, RelativeScale, RelativeScaleLens},
     game_camera_plugin::GameCamera,
-    game_scene_plugin::{GameData, GameEntity, Player},
+    game_scene_plugin::{
+        GameData, GameEntity, Player, PlayerRest, PLAYER_MASS, PLAYER_RADIUS, PLAYER_RESTITUTION,
+    },
 };
 use crate::{
     common::plugins::user_input_plugin::{UserInput, UserInputPosition},
-    game::game_plugin::GameState,
+    game::game_plugin::{GameState, PHYSICS_TIMESTEP},
     log,
     resources::{
         game_assets::{GameAnimationSource, GameAssets, GameMaterial, GameScene},
@@ -16,6 +18,7 @@ use crate::{
 use bevy::{prelude::*, scene::SceneInstance};
 use bevy_rapier3d::prelude::*;
 use bevy_tweening::{Animator, EaseFunction, RepeatCount, RepeatStrategy, Tween};
+use bevy_vector_shapes::prelude::*;
 use std::time::Duration;
 
 pub struct AimingPlugin;
@@ -23,14 +26,18 @@ pub struct AimingPlugin;
 impl Plugin for AimingPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<DragInfo>()
+            .init_resource::<BallReady>()
+            .add_event::<ShotFired>()
             .add_systems(
                 Update,
                 (
+                    update_ball_ready,
                     cancel_player_aim,
                     aim_player,
                     fire_player,
                     initialize_arrow_components,
                     update_arrow,
+                    draw_trajectory_preview.after(update_arrow),
                 )
                     .run_if(in_state(GameState::Playing))
                     .run_if(in_state(AppState::InGame)),
@@ -86,6 +93,7 @@ impl GameAnimationSource for ArrowAnimationPlayer {
 pub struct ArrowScene {
     power: f32,
     angle: f32,
+    spin: Vec2,
 }
 
 #[derive(Component)]
@@ -175,6 +183,7 @@ fn update_arrow(
 
     arrow_scene.power = power;
     arrow_scene.angle = angle;
+    arrow_scene.spin = drag_info.spin;
 }
 
 fn adjust_arrow(
@@ -209,8 +218,14 @@ fn adjust_arrow(
         return;
     };
 
+    // bank the arrow for side spin and pitch it for top or back spin
+    const MAX_TILT: f32 = std::f32::consts::FRAC_PI_6;
     let transform = player_transform
-        .with_rotation(Quat::from_rotation_z(arrow_scene.angle))
+        .with_rotation(
+            Quat::from_rotation_z(arrow_scene.angle)
+                * Quat::from_rotation_y(arrow_scene.spin.x * MAX_TILT)
+                * Quat::from_rotation_x(-arrow_scene.spin.y * MAX_TILT),
+        )
         .with_scale(0.65.lerp(1.10, arrow_scene.power) * arrow_transform.scale);
 
     if *arrow_visibility != visibility {
@@ -249,15 +264,27 @@ fn get_power_color(power: f32) -> Color {
     Color::rgb_linear_from_array(Vec3::ZERO.lerp(rgb, BRIGHTNESS))
 }
 
+/// Whether the player has settled and a new shot can be aimed.
+#[derive(Resource, Default, Deref, Clone, Copy, PartialEq, Eq)]
+pub struct BallReady(bool);
+
+fn update_ball_ready(mut ball_ready: ResMut<BallReady>, player: Query<&PlayerRest, With<Player>>) {
+    let ready = player.iter().next().is_some_and(|rest| rest.resting);
+    ball_ready.set_if_neq(BallReady(ready));
+}
+
 #[derive(Resource, Default, Deref, DerefMut, Clone, Copy)]
 pub struct DragInfo(Option<DragInfoData>);
 
 impl DragInfo {
-    pub fn start(&mut self, point: Vec2, user_input: UserInput) {
+    pub fn start(&mut self, point: Vec2, user_input: UserInput, source: AimSource) {
         **self = Some(DragInfoData {
             point,
             user_input,
+            source,
             confirmed: false,
+            spin: Vec2::ZERO,
+            spin_input: None,
         });
     }
 }
@@ -266,28 +293,101 @@ impl DragInfo {
 pub struct DragInfoData {
     point: Vec2,
     user_input: UserInput,
+    source: AimSource,
     confirmed: bool,
+    /// Side (x) and top (y) spin of the shot, both in `-1.0..=1.0`.
+    spin: Vec2,
+    spin_input: Option<SpinInput>,
+}
+
+/// Device aiming the shot. The mouse and the first finger on touch screens
+/// share the same user input id.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AimSource {
+    Mouse,
+    Touch,
+}
+
+/// Input currently adjusting the spin, with its position and the spin when it
+/// started.
+#[derive(Debug, Clone, Copy)]
+struct SpinInput {
+    user_input: UserInput,
+    origin: Vec2,
+    spin: Vec2,
 }
 
 fn cancel_player_aim(mut drag_info: ResMut<DragInfo>, user_input: Res<Inputs<UserInput>>) {
-    if user_input.iter_pressed().count() > 1 {
+    let Some(drag_info_data) = **drag_info else {
+        return;
+    };
+    // any other input cancels the aim, except for the second touch adjusting the spin
+    let max_inputs = match drag_info_data.source {
+        AimSource::Mouse => 1,
+        AimSource::Touch => 2,
+    };
+    if user_input.iter_pressed().count() > max_inputs {
         **drag_info = None;
     }
 }
 
 fn aim_player(
+    window: Query<&Window>,
     user_input_position: Res<UserInputPosition>,
     user_input: Res<Inputs<UserInput>>,
+    key_input: Res<ButtonInput<KeyCode>>,
     mut drag_info: ResMut<DragInfo>,
 ) {
+    // offset of the spin input over the whole spin range, relative to the window height
+    const SPIN_RANGE: f32 = 0.25;
+
+    let Some(window) = window.iter().next() else {
+        return;
+    };
+
     let Some(drag_info_data) = &mut **drag_info else {
         return;
     };
 
-    if let Some(cursor_position) = user_input_position.get(*drag_info_data.user_input) {
-        drag_info_data.point = cursor_position;
-    } else {
-        log!("no cursor position");
+    // the mouse adjusts the spin instead of aiming while shift is held,
+    // touch screens use a second finger
+    let spin_user_input = match drag_info_data.source {
+        AimSource::Mouse => key_input
+            .any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight])
+            .then_some(drag_info_data.user_input),
+        AimSource::Touch => user_input
+            .iter_pressed()
+            .copied()
+            .find(|input| *input != drag_info_data.user_input),
+    };
+    let spin_position =
+        spin_user_input.and_then(|input| Some((input, user_input_position.get(*input)?)));
+
+    match (spin_position, drag_info_data.spin_input) {
+        (Some((input, position)), Some(spin_input)) if spin_input.user_input == input => {
+            let offset = (position - spin_input.origin) / (window.height() * SPIN_RANGE);
+            drag_info_data.spin =
+                (spin_input.spin + Vec2::new(offset.x, -offset.y)).clamp(Vec2::NEG_ONE, Vec2::ONE);
+        }
+        (Some((input, position)), _) => {
+            drag_info_data.spin_input = Some(SpinInput {
+                user_input: input,
+                origin: position,
+                spin: drag_info_data.spin,
+            });
+        }
+        (None, _) => drag_info_data.spin_input = None,
+    }
+
+    let aiming = drag_info_data
+        .spin_input
+        .is_none_or(|spin_input| spin_input.user_input != drag_info_data.user_input);
+    if aiming {
+        if let Some(cursor_position) = user_input_position.get(*drag_info_data.user_input) {
+            drag_info_data.point = cursor_position;
+        } else {
+            log!("no cursor position");
+        }
     }
 
     if user_input.just_released(drag_info_data.user_input) {
@@ -295,16 +395,22 @@ fn aim_player(
     }
 }
 
-fn fire_player(
-    mut player: Query<(&Transform, &mut ExternalImpulse), With<Player>>,
+/// Sent with the impulse of every shot fired by the player.
+#[derive(Event, Clone, Copy)]
+pub struct ShotFired(pub ExternalImpulse);
+
+pub fn fire_player(
+    mut player: Query<(&Transform, &mut ExternalImpulse, &mut PlayerRest), With<Player>>,
     arrow: Query<&ArrowScene>,
     camera: Query<(&Camera, &GlobalTransform), With<GameCamera>>,
     mut drag_info: ResMut<DragInfo>,
     mut game_data: ResMut<GameData>,
+    ball_ready: Res<BallReady>,
+    mut shot_fired: EventWriter<ShotFired>,
 ) {
     let (camera, camera_transform) = camera.single();
 
-    let Some((transform, mut impulse)) = player.iter_mut().next() else {
+    let Some((transform, mut impulse, mut rest)) = player.iter_mut().next() else {
         return;
     };
 
@@ -312,6 +418,11 @@ fn fire_player(
         return;
     };
 
+    if !**ball_ready {
+        **drag_info = None;
+        return;
+    }
+
     if drag_info_data.confirmed {
         if let Some(new_impulse) = calculate_impulse(
             transform,
@@ -322,6 +433,8 @@ fn fire_player(
             camera_transform,
         ) {
             *impulse = new_impulse;
+            rest.reset();
+            shot_fired.send(ShotFired(new_impulse));
         }
         **drag_info = None;
     }
@@ -335,19 +448,187 @@ fn calculate_impulse(
     camera: &Camera,
     camera_transform: &GlobalTransform,
 ) -> Option<ExternalImpulse> {
-    let arrow = arrow.iter().next()?;
+    let push = get_shot_impulse(
+        transform,
+        drag_info_data,
+        arrow.iter().next()?,
+        camera,
+        camera_transform,
+    )?;
+    game_data.shots -= 1;
+
+    Some(ExternalImpulse {
+        impulse: push,
+        torque_impulse: get_spin_impulse(push, drag_info_data.spin),
+    })
+}
+
+/// Angular impulse spinning the ball around its travel direction for side
+/// spin, so the friction curves it, and around the horizontal axis for top or
+/// back spin.
+fn get_spin_impulse(push: Vec3, spin: Vec2) -> Vec3 {
+    // angular speed at full spin relative to the rolling speed of the shot
+    const MAX_SPIN: f32 = 1.5;
+
+    let forward = Vec3::new(push.x, push.y, 0.0).normalize_or_zero();
+    let axis = forward * spin.x + Vec3::Z.cross(forward) * spin.y;
+    // solid sphere inertia of 2/5 m r² at the angular speed of rolling v / r
+    axis * 0.4 * PLAYER_RADIUS * push.length() * MAX_SPIN
+}
+
+fn get_shot_impulse(
+    transform: &Transform,
+    drag_info_data: &DragInfoData,
+    arrow: &ArrowScene,
+    camera: &Camera,
+    camera_transform: &GlobalTransform,
+) -> Option<Vec3> {
     if arrow.power <= 0.0 {
         return None;
     }
     let ray = camera.viewport_to_world(camera_transform, drag_info_data.point)?;
     let distance = ray.intersect_plane(transform.translation, Plane3d::new(Vec3::Z))?;
     let point = ray.get_point(distance);
-    game_data.shots -= 1;
-    let push = (transform.translation - point).normalize() * arrow.power * 250.0;
 
-    Some(ExternalImpulse::at_point(
-        push,
-        transform.translation,
-        transform.translation,
-    ))
+    Some((transform.translation - point).normalize() * arrow.power * 250.0)
+}
+
+#[allow(clippy::too_many_arguments)]
+fn draw_trajectory_preview(
+    mut painter: ShapePainter,
+    player: Query<(Entity, &Transform, &Damping, &GravityScale), With<Player>>,
+    arrow: Query<&ArrowScene>,
+    camera: Query<(&Camera, &GlobalTransform), With<GameCamera>>,
+    drag_info: Res<DragInfo>,
+    game_data: Res<GameData>,
+    game_assets: Res<GameAssets>,
+    rapier_context: Res<RapierContext>,
+    rapier_config: Res<RapierConfiguration>,
+) {
+    const DOT_SPACING: f32 = 0.25;
+    const DOT_RADIUS: f32 = 0.04;
+    const MAX_STEPS: usize = 600;
+    const MAX_HITS: usize = 4;
+    // casts starting in contact are moved off the surface to make progress
+    const CONTACT_SKIN: f32 = 0.001;
+    // slower hits along the surface normal roll instead of bouncing
+    const BOUNCE_SPEED: f32 = 1.0;
+
+    let Some(max_length) = game_data
+        .level
+        .and_then(|level| game_assets.levels.get(level).trajectory_preview)
+    else {
+        return;
+    };
+    let Some(drag_info_data) = **drag_info else {
+        return;
+    };
+    let Some((player_entity, transform, damping, gravity_scale)) = player.iter().next() else {
+        return;
+    };
+    let Some(arrow) = arrow.iter().next() else {
+        return;
+    };
+    let Some((camera, camera_transform)) = camera.iter().next() else {
+        return;
+    };
+    let Some(impulse) =
+        get_shot_impulse(transform, &drag_info_data, arrow, camera, camera_transform)
+    else {
+        return;
+    };
+
+    painter.set_config(ShapeConfig {
+        alignment: Alignment::Billboard,
+        ..ShapeConfig::default_3d()
+    });
+    let color = get_power_color(arrow.power);
+
+    let collider = Collider::ball(PLAYER_RADIUS);
+    let filter = QueryFilter::new()
+        .exclude_rigid_body(player_entity)
+        .exclude_sensors();
+    let gravity = rapier_config.gravity * gravity_scale.0;
+    let damping = 1.0 / (1.0 + PHYSICS_TIMESTEP * damping.linear_damping);
+
+    let mut position = transform.translation;
+    let mut velocity = impulse / PLAYER_MASS;
+    let mut length = 0.0;
+    let mut next_dot = DOT_SPACING;
+    let mut bounced = false;
+
+    'steps: for _ in 0..MAX_STEPS {
+        velocity = (velocity + gravity * PHYSICS_TIMESTEP) * damping;
+
+        // the ball can hit several surfaces during a step, e.g. when rolling into a wall
+        let mut remaining = PHYSICS_TIMESTEP;
+        for _ in 0..MAX_HITS {
+            let hit = rapier_context
+                .cast_shape(
+                    position,
+                    Quat::IDENTITY,
+                    velocity,
+                    &collider,
+                    remaining,
+                    false,
+                    filter,
+                )
+                .and_then(|(entity, toi)| match toi.details {
+                    Some(details) => Some((toi.toi, -details.normal1)),
+                    // touching at the start of the cast, e.g. resting on the floor
+                    None => {
+                        let normal = get_contact_normal(&rapier_context, position, entity, filter)?;
+                        position += normal * CONTACT_SKIN;
+                        Some((toi.toi, normal))
+                    }
+                });
+            let step = hit.map_or(remaining, |(toi, _)| toi);
+            let next_position = position + velocity * step;
+
+            let segment = next_position - position;
+            while next_dot <= length + segment.length() && next_dot <= max_length {
+                let point = position + segment.normalize() * (next_dot - length);
+                painter.color = color.with_a(1.0 - next_dot / max_length);
+                painter.set_translation(point);
+                painter.circle(DOT_RADIUS);
+                next_dot += DOT_SPACING;
+            }
+            length += segment.length();
+            position = next_position;
+            remaining -= step;
+            if length >= max_length {
+                break 'steps;
+            }
+
+            let Some((_, normal)) = hit else {
+                break;
+            };
+            let normal_speed = velocity.dot(normal);
+            match -normal_speed > BOUNCE_SPEED {
+                true if bounced => break 'steps,
+                true => {
+                    velocity -= normal * normal_speed * (1.0 + PLAYER_RESTITUTION);
+                    bounced = true;
+                }
+                false => velocity -= normal * normal_speed,
+            }
+        }
+    }
+}
+
+/// Outward normal of the collider surface touched by the ball at `position`.
+fn get_contact_normal(
+    rapier_context: &RapierContext,
+    position: Vec3,
+    entity: Entity,
+    filter: QueryFilter,
+) -> Option<Vec3> {
+    let touched = |e| e == entity;
+    let (_, projection) =
+        rapier_context.project_point(position, false, filter.predicate(&touched))?;
+    let normal = (position - projection.point).try_normalize()?;
+    match projection.is_inside {
+        true => Some(-normal),
+        false => Some(normal),
+    }
 }
@@ -0,0 +1,127 @@
+use super::game_scene_plugin::{collect, Collected, GameData, GameEntity, Player};
+use crate::{
+    game::game_plugin::{GameState, PHYSICS_SUBSTEP_DT},
+    AppState,
+};
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::*;
+
+pub struct BreakablePlugin;
+
+impl Plugin for BreakablePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            break_on_contact_force
+                .run_if(in_state(AppState::InGame))
+                .run_if(in_state(GameState::Playing)),
+        );
+    }
+}
+
+/// Object breaking when the player hits it hard enough.
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct GameBreakable {
+    /// Minimal impulse of the player breaking the object.
+    pub threshold: f32,
+    pub reward: i32,
+}
+
+impl GameBreakable {
+    pub const DEFAULT_THRESHOLD: f32 = 100.0;
+
+    pub fn collider_bundle(&self) -> impl Bundle + Clone {
+        (
+            *self,
+            ActiveEvents::CONTACT_FORCE_EVENTS,
+            ContactForceEventThreshold(self.force_threshold()),
+        )
+    }
+
+    /// Contact force of a physics substep matching the impulse threshold.
+    fn force_threshold(&self) -> f32 {
+        self.threshold / PHYSICS_SUBSTEP_DT
+    }
+
+    fn breaks(&self, total_force_magnitude: f32) -> bool {
+        total_force_magnitude * PHYSICS_SUBSTEP_DT >= self.threshold
+    }
+}
+
+/// Scene replacing a breakable object when it breaks.
+#[derive(Component, Clone)]
+pub struct BreakableDebris(pub Handle<Scene>);
+
+/// Debris of an object broken since the last shot, removed when the shot is
+/// rewound.
+#[derive(Component, Clone, Copy)]
+pub struct RecentDebris;
+
+#[allow(clippy::too_many_arguments)]
+fn break_on_contact_force(
+    mut commands: Commands,
+    mut contact_force_events: EventReader<ContactForceEvent>,
+    mut game_data: ResMut<GameData>,
+    player: Query<Entity, With<Player>>,
+    breakables: Query<(&Parent, &GameBreakable)>,
+    objects: Query<(&GlobalTransform, Option<&BreakableDebris>)>,
+    collected: Query<(), With<Collected>>,
+    children: Query<&Children>,
+) {
+    let Some(player_entity) = player.iter().next() else {
+        return;
+    };
+
+    let mut broken = Vec::new();
+
+    for event in contact_force_events.read() {
+        let breakable_entity = match player_entity {
+            e if e == event.collider1 => event.collider2,
+            e if e == event.collider2 => event.collider1,
+            _ => continue,
+        };
+        let Ok((parent, breakable)) = breakables.get(breakable_entity) else {
+            continue;
+        };
+        let object_entity = **parent;
+        if broken.contains(&object_entity)
+            || collected.contains(object_entity)
+            || !breakable.breaks(event.total_force_magnitude)
+        {
+            continue;
+        }
+        broken.push(object_entity);
+
+        if let Ok((transform, Some(debris))) = objects.get(object_entity) {
+            commands.spawn((
+                SceneBundle {
+                    scene: debris.0.clone(),
+                    transform: transform.compute_transform(),
+                    ..Default::default()
+                },
+                GameEntity,
+                RecentDebris,
+            ));
+        }
+        collect(&mut commands, &children, object_entity);
+        game_data.points += breakable.reward;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_force_threshold() {
+        let breakable = GameBreakable {
+            threshold: 100.0,
+            reward: 0,
+        };
+        let force = breakable.force_threshold();
+        // contact forces are reported per substep: 100 / (1/60 / 4)
+        assert!((force - 24_000.0).abs() < 0.01);
+        assert!(breakable.breaks(force * 1.01));
+        assert!(!breakable.breaks(force * 0.99));
+    }
+}
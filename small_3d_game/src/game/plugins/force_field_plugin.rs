@@ -0,0 +1,180 @@
+use super::game_scene_plugin::{Player, PLAYER_MASS, PLAYER_RADIUS};
+use crate::game::game_plugin::PhysicsSchedule;
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::*;
+use serde_derive::Deserialize;
+
+pub struct ForceFieldPlugin;
+
+impl Plugin for ForceFieldPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            PhysicsSchedule,
+            apply_force_fields.before(PhysicsSet::SyncBackend),
+        );
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+pub enum ForceFieldMode {
+    Wind,
+    Radial,
+    Gravity,
+}
+
+/// Water volume pushing the player up and slowing it down.
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct GameWater {
+    /// Buoyancy of the fully submerged player relative to its weight.
+    pub buoyancy: f32,
+    /// Additional damping of the fully submerged player.
+    pub damping: f32,
+}
+
+impl GameWater {
+    pub const DEFAULT_BUOYANCY: f32 = 1.5;
+    pub const DEFAULT_DAMPING: f32 = 3.0;
+}
+
+/// Submerged part of the player volume and the damping of the water.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq)]
+pub struct Submerged {
+    pub fraction: f32,
+    pub damping: f32,
+}
+
+/// Volume fraction of the player sphere below the water `depth` measured
+/// from its bottom.
+fn submerged_fraction(depth: f32) -> f32 {
+    let depth = depth.clamp(0.0, 2.0 * PLAYER_RADIUS);
+    depth * depth * (3.0 * PLAYER_RADIUS - depth) / (4.0 * PLAYER_RADIUS.powi(3))
+}
+
+/// Sensor volume accelerating the player while it overlaps it.
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub enum GameForceField {
+    /// Acceleration along the local forward (Y) axis.
+    Wind(f32),
+    /// Acceleration towards the field origin, negative values repel.
+    Radial(f32),
+    /// Gravity along the local down (-Z) axis, replacing the global gravity.
+    Gravity(f32),
+}
+
+impl GameForceField {
+    pub const DEFAULT_WIND: f32 = 5.0;
+    pub const DEFAULT_RADIAL: f32 = 5.0;
+    pub const DEFAULT_GRAVITY: f32 = 9.81;
+
+    pub fn new(mode: ForceFieldMode, strength: Option<f32>) -> Self {
+        match mode {
+            ForceFieldMode::Wind => Self::Wind(strength.unwrap_or(Self::DEFAULT_WIND)),
+            ForceFieldMode::Radial => Self::Radial(strength.unwrap_or(Self::DEFAULT_RADIAL)),
+            ForceFieldMode::Gravity => Self::Gravity(strength.unwrap_or(Self::DEFAULT_GRAVITY)),
+        }
+    }
+}
+
+pub fn apply_force_fields(
+    mut player: Query<
+        (
+            Entity,
+            &Transform,
+            &GravityScale,
+            &mut ExternalForce,
+            &mut Submerged,
+        ),
+        With<Player>,
+    >,
+    fields: Query<(&GlobalTransform, &GameForceField)>,
+    waters: Query<&GameWater>,
+    rapier_context: Res<RapierContext>,
+    rapier_config: Res<RapierConfiguration>,
+) {
+    let Some((player_entity, transform, gravity_scale, mut force, mut submerged)) =
+        player.iter_mut().next()
+    else {
+        return;
+    };
+
+    let mut acceleration = Vec3::ZERO;
+    let mut gravity = None;
+    let mut water = Submerged::default();
+
+    for (e1, e2, intersecting) in rapier_context.intersection_pairs_with(player_entity) {
+        if !intersecting {
+            continue;
+        }
+        let other = if e1 == player_entity { e2 } else { e1 };
+
+        if let Ok(game_water) = waters.get(other) {
+            // the water surface is where a ray from the bottom of the player leaves the water
+            let bottom = transform.translation - Vec3::Z * PLAYER_RADIUS;
+            let depth = rapier_context
+                .cast_ray(
+                    bottom,
+                    Vec3::Z,
+                    2.0 * PLAYER_RADIUS,
+                    false,
+                    QueryFilter::new().predicate(&|e| e == other),
+                )
+                .map(|(_, toi)| toi)
+                .unwrap_or(2.0 * PLAYER_RADIUS);
+            let fraction = submerged_fraction(depth);
+            if fraction > water.fraction {
+                water = Submerged {
+                    fraction,
+                    damping: game_water.damping,
+                };
+            }
+            acceleration -=
+                rapier_config.gravity * gravity_scale.0 * game_water.buoyancy * fraction;
+            continue;
+        }
+
+        let Ok((field_transform, field)) = fields.get(other) else {
+            continue;
+        };
+
+        let rotation = field_transform.compute_transform().rotation;
+        match *field {
+            GameForceField::Wind(strength) => acceleration += rotation * Vec3::Y * strength,
+            GameForceField::Radial(strength) => {
+                acceleration += (field_transform.translation() - transform.translation)
+                    .normalize_or_zero()
+                    * strength
+            }
+            GameForceField::Gravity(strength) => {
+                gravity.get_or_insert(rotation * Vec3::NEG_Z * strength);
+            }
+        }
+    }
+
+    if let Some(gravity) = gravity {
+        // cancel out the global gravity applied by the physics engine
+        acceleration += (gravity - rapier_config.gravity) * gravity_scale.0;
+    }
+
+    force.set_if_neq(ExternalForce {
+        force: acceleration * PLAYER_MASS,
+        torque: Vec3::ZERO,
+    });
+    submerged.set_if_neq(water);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_submerged_fraction() {
+        assert_eq!(submerged_fraction(-1.0), 0.0);
+        assert_eq!(submerged_fraction(0.0), 0.0);
+        assert!((submerged_fraction(PLAYER_RADIUS) - 0.5).abs() < 1e-5);
+        assert!((submerged_fraction(2.0 * PLAYER_RADIUS) - 1.0).abs() < 1e-5);
+        assert_eq!(
+            submerged_fraction(1.0),
+            submerged_fraction(2.0 * PLAYER_RADIUS)
+        );
+    }
+}
@@ -84,6 +84,7 @@ fn zoom_camera(
     mut scroll: EventReader<MouseWheel>,
     user_input: Res<Inputs<UserInput>>,
     user_input_position: Res<UserInputPosition>,
+    drag_info: Res<DragInfo>,
     mut current: Local<Option<((u64, u64), f32)>>,
 ) {
     let mut camera = camera.single_mut();
@@ -92,6 +93,12 @@ fn zoom_camera(
         camera.1.distance(-scroll.y.clamp(-1.0, 1.0) * 0.5);
     }
 
+    // the second touch adjusts the spin while aiming
+    if drag_info.is_some() {
+        *current = None;
+        return;
+    }
+
     let Some(((first, second), dist)) = current.or_else(|| {
         let mut iter = user_input.iter_pressed();
         let first = **iter.next()?;
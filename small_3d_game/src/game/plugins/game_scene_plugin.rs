@@ -1,26 +1,37 @@
 use super::{
-    aiming_plugin::{spawn_arrow, DragInfo},
+    aiming_plugin::{spawn_arrow, ArrowScene, DragInfo},
+    breakable_plugin::BreakableDebris,
     custom_tweening_plugin::GameTween,
+    force_field_plugin::{apply_force_fields, Submerged},
     game_camera_plugin::GameCamera,
+    pad_plugin::GameBouncer,
+    power_up_plugin::{ActivePowerUps, GamePowerUp, PowerUpKind},
+    replay_plugin::PhysicsTick,
+    rewind_plugin::ShotHistory,
 };
 use crate::{
     game::{
-        game_plugin::GameState,
+        game_plugin::{GameState, PhysicsSchedule},
+        level_object::{GameLevelObjectType, LevelObjectDescriptor},
         plugins::custom_tweening_plugin::{
             RelativeScale, RelativeScaleLens, Rotation, RotationLens,
         },
     },
     log,
     resources::{
-        game_assets::{GameAnimationSource, GameAssets, GameLevel, GameScene},
+        game_assets::{GameAssets, GameLevel, GameScene},
         random::Random,
     },
     AppState,
 };
-use bevy::{input::keyboard::KeyboardInput, prelude::*, scene::SceneInstance};
+use bevy::{
+    ecs::system::EntityCommands, gltf::GltfExtras, input::keyboard::KeyboardInput, prelude::*,
+    scene::SceneInstance,
+};
 use bevy_rapier3d::prelude::*;
 use bevy_tweening::{Animator, EaseFunction, EaseMethod, RepeatCount, RepeatStrategy, Tween};
 use rand::Rng;
+use serde_derive::Deserialize;
 use std::time::Duration;
 
 #[derive(Component, Clone, Copy)]
@@ -35,6 +46,43 @@ pub struct GameData {
     pub result: Option<bool>,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Reflect, Deserialize)]
+pub enum KeyColor {
+    Red,
+    Green,
+    Blue,
+    Yellow,
+}
+
+impl KeyColor {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "Red" => Some(Self::Red),
+            "Green" => Some(Self::Green),
+            "Blue" => Some(Self::Blue),
+            "Yellow" => Some(Self::Yellow),
+            _ => None,
+        }
+    }
+}
+
+/// Items collected during the current run of a level.
+#[derive(Resource, Default, Clone, Reflect)]
+#[reflect(Resource)]
+pub struct GameInventory {
+    pub keys: Vec<KeyColor>,
+}
+
+impl GameInventory {
+    pub fn has_key(&self, color: KeyColor) -> bool {
+        self.keys.contains(&color)
+    }
+}
+
+/// Position the player returns to after leaving the bounds.
+#[derive(Resource, Default, Deref, DerefMut)]
+pub struct RespawnPoint(Option<Vec3>);
+
 #[derive(Event)]
 struct LevelChanged;
 
@@ -47,6 +95,9 @@ impl Plugin for GameScenePlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<GameData>()
             .register_type::<GameData>()
+            .init_resource::<GameInventory>()
+            .register_type::<GameInventory>()
+            .init_resource::<RespawnPoint>()
             .add_event::<SetGameLevel>()
             .add_event::<LevelChanged>()
             .add_systems(
@@ -54,14 +105,27 @@ impl Plugin for GameScenePlugin {
                 (
                     initialize_game_scene,
                     initialize_game_scene_components,
-                    reward_points_on_collision,
+                    collect_on_collision,
                     reload_scene,
                     lose_on_pass_through_bounds,
+                    lose_on_rest_without_shots,
+                    hurt_on_hazard_collision,
+                    touch_checkpoints,
                     win_on_pass_through_goal,
+                    open_unlocked_doors.run_if(resource_changed::<GameInventory>),
                 )
                     .run_if(in_state(AppState::InGame))
                     .run_if(in_state(GameState::Playing)),
             )
+            .add_systems(
+                PhysicsSchedule,
+                (
+                    apply_player_damping
+                        .after(apply_force_fields)
+                        .before(PhysicsSet::SyncBackend),
+                    detect_player_rest.after(PhysicsSet::Writeback),
+                ),
+            )
             .add_systems(Update, (reset_state, spawn_game_scene, set_game_scene))
             .add_systems(OnEnter(GameState::Paused), pause_animation_players)
             .add_systems(OnEnter(GameState::Playing), resume_animation_players);
@@ -70,11 +134,15 @@ impl Plugin for GameScenePlugin {
 
 fn set_game_scene(
     mut game_data: ResMut<GameData>,
+    game_assets: Res<GameAssets>,
     mut set_game_level: EventReader<SetGameLevel>,
     mut level_changed: EventWriter<LevelChanged>,
 ) {
     for set_game_level in set_game_level.read() {
-        *game_data = set_game_level.0.map(Into::into).unwrap_or_default();
+        *game_data = set_game_level
+            .0
+            .map(|level| game_assets.levels.get_game_data(level))
+            .unwrap_or_default();
         if set_game_level.0.is_none() {
             log!("Game level is not set");
         }
@@ -96,10 +164,16 @@ fn reload_scene(
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn reset_state(
     mut commands: Commands,
     mut camera: Query<&mut GameCamera>,
     mut drag_info: ResMut<DragInfo>,
+    mut active_power_ups: ResMut<ActivePowerUps>,
+    mut inventory: ResMut<GameInventory>,
+    mut respawn_point: ResMut<RespawnPoint>,
+    mut shot_history: ResMut<ShotHistory>,
+    mut physics_tick: ResMut<PhysicsTick>,
     entities: Query<(Entity, &GameEntity)>,
     mut level_changed: EventReader<LevelChanged>,
 ) {
@@ -118,6 +192,13 @@ fn reset_state(
 
     // Reset drag info
     **drag_info = None;
+
+    // Reset power ups and collected items
+    active_power_ups.clear();
+    *inventory = GameInventory::default();
+    **respawn_point = None;
+    *shot_history = ShotHistory::default();
+    *physics_tick = PhysicsTick::default();
 }
 
 #[derive(Component)]
@@ -129,11 +210,13 @@ struct GameSceneEntity;
 #[derive(Component)]
 pub struct GameSceneAnimationPlayer(pub GameLevel);
 
-impl GameAnimationSource for GameSceneAnimationPlayer {
-    fn get_animation_filename(&self) -> &str {
-        self.0.get_filename()
-    }
-}
+/// Game scene animation which does not start playing on its own.
+#[derive(Component, Clone, Copy)]
+pub struct PausedAnimation;
+
+/// Animation speed before the game was paused.
+#[derive(Component, Clone, Copy)]
+struct PausedSpeed(f32);
 
 fn spawn_game_scene(
     mut commands: Commands,
@@ -153,7 +236,7 @@ fn spawn_game_scene(
                 ..default()
             })
             .insert((GameSceneScene, GameEntity));
-        rng.reset(game_level);
+        rng.reset(&game_assets.levels.get(game_level).id);
     }
 }
 
@@ -180,68 +263,67 @@ fn initialize_game_scene_components(
     }
 }
 
-#[derive(Component, Clone, Copy, Default)]
-struct GamePoints {
-    reward: i32,
-}
-
-enum GameLevelObjectType {
-    Object,
-    Bounds,
-    Spawn,
-    Goal(Vec2),
-    Point(GamePoints),
-}
-
-impl TryFrom<&Name> for GameLevelObjectType {
-    type Error = ();
-
-    fn try_from(value: &Name) -> Result<Self, ()> {
-        match value.as_str() {
-            s if s.starts_with("Object") => Ok(Self::Object),
-            "Bounds" => Ok(Self::Bounds),
-            "Spawn" => Ok(Self::Spawn),
-            s if s.starts_with("Goal") => s
-                .split_once('_')
-                .and_then(|(_, d)| d.get(..2))
-                .and_then(|d| match d {
-                    "X+" => Some(Vec2::X),
-                    "X-" => Some(-Vec2::X),
-                    "Y+" => Some(Vec2::Y),
-                    "Y-" => Some(-Vec2::Y),
-                    _ => None,
-                })
-                .map(Self::Goal)
-                .ok_or(()),
-
-            s if s.starts_with("Point") => s
-                .split_once('_')
-                .map(|(_, d)| d.chars().take_while(|c| c != &'.').collect::<String>())
-                .and_then(|d| d.parse().ok())
-                .map(|reward| GamePoints { reward })
-                .map(Self::Point)
-                .ok_or(()),
-
-            _ => Err(()),
-        }
+#[derive(Component, Clone, Copy, Default, Debug, PartialEq)]
+pub struct GamePoints {
+    pub reward: i32,
+}
+
+/// Collectible, broken object or door without an animation removed since the
+/// last shot. It stays hidden with disabled colliders until the shot is rewound
+/// or the next shot is fired.
+#[derive(Component, Clone, Copy)]
+pub struct Collected;
+
+pub fn collect(commands: &mut Commands, children: &Query<&Children>, entity: Entity) {
+    commands
+        .entity(entity)
+        .try_insert((Collected, Visibility::Hidden));
+    for child in children.iter_descendants(entity) {
+        commands.entity(child).try_insert(ColliderDisabled);
     }
 }
 
+type SceneObject<'a> = (
+    Entity,
+    &'a Name,
+    Option<&'a GltfExtras>,
+    Option<&'a Children>,
+);
+
 #[allow(clippy::too_many_arguments)]
 fn initialize_game_scene(
     mut commands: Commands,
-    entities: Query<(Entity, &Name, Option<&Children>), Added<Name>>,
+    entities: Query<SceneObject, Added<Name>>,
     meshes: Res<Assets<Mesh>>,
     mesh_entities: Query<&Handle<Mesh>>,
     transforms: Query<&Transform>,
     animation_players: Query<&AnimationPlayer>,
     game_assets: Res<GameAssets>,
+    asset_server: Res<AssetServer>,
+    mut respawn_point: ResMut<RespawnPoint>,
     mut rng: NonSendMut<Random>,
 ) {
-    for (entity, name, children) in entities.iter() {
-        let Ok(object_type) = GameLevelObjectType::try_from(name) else {
+    for (entity, name, extras, children) in entities.iter() {
+        let Some(descriptor) =
+            LevelObjectDescriptor::parse(name, extras.map(|extras| extras.value.as_str()))
+        else {
             continue;
         };
+        let object_type = match GameLevelObjectType::try_from(&descriptor) {
+            Ok(object_type) => object_type,
+            Err(e) => {
+                log!("{}: invalid level object: {}", name, e);
+                continue;
+            }
+        };
+
+        if let Some(children) = children {
+            insert_surface_into_entities(&mut commands, children, name, &descriptor, &game_assets);
+        }
+
+        if descriptor.paused == Some(true) {
+            commands.entity(entity).try_insert(PausedAnimation);
+        }
 
         match object_type {
             GameLevelObjectType::Object => {
@@ -270,20 +352,48 @@ fn initialize_game_scene(
                 let Ok(transform) = transforms.get(entity) else {
                     continue;
                 };
-                spawn_player(
-                    &mut commands,
-                    &game_assets,
-                    transform.translation + Vec3::Z * PLAYER_RADIUS,
-                );
+                let position = transform.translation + Vec3::Z * PLAYER_RADIUS;
+                spawn_player(&mut commands, &game_assets, position);
+                respawn_point.get_or_insert(position);
             }
-            GameLevelObjectType::Goal(dir) => {
+            GameLevelObjectType::Checkpoint => {
+                let Ok(transform) = transforms.get(entity) else {
+                    continue;
+                };
                 if let Some(children) = children {
                     insert_collider_into_entities(
                         &mut commands,
                         children,
                         &meshes,
                         &mesh_entities,
-                        (Sensor, GameGoal(dir)),
+                        (
+                            GameCheckpoint {
+                                position: transform.translation + Vec3::Z * PLAYER_RADIUS,
+                            },
+                            Sensor,
+                        ),
+                    );
+                }
+            }
+            GameLevelObjectType::Goal(goal) => {
+                if let Some(children) = children {
+                    insert_collider_into_entities(
+                        &mut commands,
+                        children,
+                        &meshes,
+                        &mesh_entities,
+                        (Sensor, goal),
+                    );
+                }
+            }
+            GameLevelObjectType::Hazard(hazard) => {
+                if let Some(children) = children {
+                    insert_collider_into_entities(
+                        &mut commands,
+                        children,
+                        &meshes,
+                        &mesh_entities,
+                        hazard,
                     );
                 }
             }
@@ -298,48 +408,247 @@ fn initialize_game_scene(
                     );
                 }
 
-                let entity_commands = &mut commands.entity(entity);
-                entity_commands.try_insert((
-                    Animator::<RelativeScale>::new(
-                        Tween::new(
-                            EaseFunction::SineInOut,
-                            Duration::from_secs_f32(rng.gen_range(0.3..1.0)),
-                            RelativeScaleLens {
-                                start: Vec3::splat(0.95),
-                                end: Vec3::splat(1.08),
-                            },
-                        )
-                        .with_repeat_strategy(RepeatStrategy::MirroredRepeat)
-                        .with_repeat_count(RepeatCount::Infinite),
-                    ),
-                    RelativeScale::default(),
-                    Rotation::new(Vec3::Z),
-                    GameTween,
-                ));
+                animate_collectible(
+                    &mut commands.entity(entity),
+                    animation_players.get(entity).is_err(),
+                    &mut rng,
+                );
+            }
+            GameLevelObjectType::PowerUp(power_up) => {
+                if let Some(children) = children {
+                    insert_collider_into_entities(
+                        &mut commands,
+                        children,
+                        &meshes,
+                        &mesh_entities,
+                        (power_up, Sensor),
+                    );
+                }
 
-                if animation_players.get(entity).is_err() {
-                    entity_commands.try_insert((
-                        Animator::<Rotation>::new(
-                            Tween::new(
-                                EaseMethod::Linear,
-                                Duration::from_secs_f32(rng.gen_range(0.7..2.0)),
-                                RotationLens,
-                            )
-                            .with_repeat_strategy(RepeatStrategy::Repeat)
-                            .with_repeat_count(RepeatCount::Infinite),
-                        ),
-                        GameTween,
+                animate_collectible(
+                    &mut commands.entity(entity),
+                    animation_players.get(entity).is_err(),
+                    &mut rng,
+                );
+            }
+            GameLevelObjectType::Booster(booster) => {
+                if let Some(children) = children {
+                    insert_collider_into_entities(
+                        &mut commands,
+                        children,
+                        &meshes,
+                        &mesh_entities,
+                        (booster, Sensor),
+                    );
+                }
+            }
+            GameLevelObjectType::Bouncer(bouncer) => {
+                if let Some(children) = children {
+                    insert_collider_into_entities(
+                        &mut commands,
+                        children,
+                        &meshes,
+                        &mesh_entities,
+                        (bouncer, GameBouncer::restitution()),
+                    );
+                }
+            }
+            GameLevelObjectType::ForceField(field) => {
+                if let Some(children) = children {
+                    insert_collider_into_entities(
+                        &mut commands,
+                        children,
+                        &meshes,
+                        &mesh_entities,
+                        (field, Sensor, Visibility::Hidden),
+                    );
+                }
+            }
+            GameLevelObjectType::Key(key) => {
+                if let Some(children) = children {
+                    insert_collider_into_entities(
+                        &mut commands,
+                        children,
+                        &meshes,
+                        &mesh_entities,
+                        (key, Sensor),
+                    );
+                }
+
+                animate_collectible(
+                    &mut commands.entity(entity),
+                    animation_players.get(entity).is_err(),
+                    &mut rng,
+                );
+            }
+            GameLevelObjectType::Door(door) => {
+                commands.entity(entity).try_insert((door, PausedAnimation));
+                if let Some(children) = children {
+                    insert_collider_into_entities(
+                        &mut commands,
+                        children,
+                        &meshes,
+                        &mesh_entities,
+                        (),
+                    );
+                }
+            }
+            GameLevelObjectType::Switch(switch) => {
+                if let Some(children) = children {
+                    insert_collider_into_entities(
+                        &mut commands,
+                        children,
+                        &meshes,
+                        &mesh_entities,
+                        (switch, Sensor),
+                    );
+                }
+            }
+            GameLevelObjectType::Breakable(breakable) => {
+                if let Some(debris) = &descriptor.debris {
+                    commands.entity(entity).try_insert(BreakableDebris(
+                        asset_server.load(format!("{}#Scene0", debris)),
                     ));
                 }
+                if let Some(children) = children {
+                    insert_collider_into_entities(
+                        &mut commands,
+                        children,
+                        &meshes,
+                        &mesh_entities,
+                        breakable.collider_bundle(),
+                    );
+                }
+            }
+            GameLevelObjectType::Dynamic(dynamic, joint) => {
+                if let Some(joint) = joint {
+                    commands.entity(entity).try_insert(joint);
+                }
+                // the physics schedule does not run while paused, freezing the props
+                commands.entity(entity).try_insert((
+                    dynamic,
+                    RigidBody::Dynamic,
+                    Velocity::zero(),
+                    Sleeping::default(),
+                    Ccd::enabled(),
+                ));
+                if let Some(children) = children {
+                    insert_dynamic_collider_into_entities(
+                        &mut commands,
+                        children,
+                        &meshes,
+                        &mesh_entities,
+                        dynamic.mass,
+                    );
+                }
+            }
+            GameLevelObjectType::Water(water) => {
+                if let Some(children) = children {
+                    insert_collider_into_entities(
+                        &mut commands,
+                        children,
+                        &meshes,
+                        &mesh_entities,
+                        (water, Sensor),
+                    );
+                }
+            }
+            GameLevelObjectType::Conveyor(conveyor) => {
+                if let Some(children) = children {
+                    insert_collider_into_entities(
+                        &mut commands,
+                        children,
+                        &meshes,
+                        &mesh_entities,
+                        conveyor,
+                    );
+                }
+            }
+            GameLevelObjectType::Portal(portal) => {
+                if let Some(children) = children {
+                    insert_collider_into_entities(
+                        &mut commands,
+                        children,
+                        &meshes,
+                        &mesh_entities,
+                        (portal, Sensor),
+                    );
+                }
             }
         }
     }
 }
 
+fn animate_collectible(entity_commands: &mut EntityCommands, rotate: bool, rng: &mut Random) {
+    entity_commands.try_insert((
+        Animator::<RelativeScale>::new(
+            Tween::new(
+                EaseFunction::SineInOut,
+                Duration::from_secs_f32(rng.gen_range(0.3..1.0)),
+                RelativeScaleLens {
+                    start: Vec3::splat(0.95),
+                    end: Vec3::splat(1.08),
+                },
+            )
+            .with_repeat_strategy(RepeatStrategy::MirroredRepeat)
+            .with_repeat_count(RepeatCount::Infinite),
+        ),
+        RelativeScale::default(),
+        Rotation::new(Vec3::Z),
+        GameTween,
+    ));
+
+    if rotate {
+        entity_commands.try_insert((
+            Animator::<Rotation>::new(
+                Tween::new(
+                    EaseMethod::Linear,
+                    Duration::from_secs_f32(rng.gen_range(0.7..2.0)),
+                    RotationLens,
+                )
+                .with_repeat_strategy(RepeatStrategy::Repeat)
+                .with_repeat_count(RepeatCount::Infinite),
+            ),
+            GameTween,
+        ));
+    }
+}
+
 #[derive(Component)]
 pub struct Player;
 
 pub const PLAYER_RADIUS: f32 = 0.2;
+pub const PLAYER_RESTITUTION: f32 = 0.3;
+pub const PLAYER_MASS: f32 = 10.0;
+const PLAYER_DAMPING: f32 = 0.5;
+
+/// Additional player damping while touching the surface.
+#[derive(Component, Clone, Copy)]
+struct RollingDamping(f32);
+
+/// Tracks whether the player has come to rest after a shot.
+#[derive(Component, Clone, Copy)]
+pub struct PlayerRest {
+    settle_time: f32,
+    pub resting: bool,
+    /// Position of the player when it last came to rest.
+    pub position: Vec3,
+}
+
+impl PlayerRest {
+    pub fn new(position: Vec3) -> Self {
+        Self {
+            settle_time: 0.0,
+            resting: false,
+            position,
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.settle_time = 0.0;
+        self.resting = false;
+    }
+}
 
 pub fn spawn_player(commands: &mut Commands, game_assets: &Res<GameAssets>, pos: Vec3) {
     commands
@@ -350,17 +659,24 @@ pub fn spawn_player(commands: &mut Commands, game_assets: &Res<GameAssets>, pos:
         })
         .try_insert((
             Player,
+            PlayerRest::new(pos),
             Sleeping::default(),
-            ExternalImpulse::default(),
+            Velocity::zero(),
+            (
+                ExternalImpulse::default(),
+                ExternalForce::default(),
+                Submerged::default(),
+            ),
             RigidBody::Dynamic,
             Collider::ball(PLAYER_RADIUS),
             Friction::coefficient(0.6),
-            Restitution::new(0.3),
+            Restitution::new(PLAYER_RESTITUTION),
+            GravityScale(1.0),
             Damping {
-                linear_damping: 0.5,
-                angular_damping: 0.5,
+                linear_damping: PLAYER_DAMPING,
+                angular_damping: PLAYER_DAMPING,
             },
-            ColliderMassProperties::Mass(10.0),
+            ColliderMassProperties::Mass(PLAYER_MASS),
             ActiveEvents::COLLISION_EVENTS,
             Ccd::enabled(),
             GameEntity,
@@ -368,6 +684,42 @@ pub fn spawn_player(commands: &mut Commands, game_assets: &Res<GameAssets>, pos:
     spawn_arrow(commands, game_assets, pos);
 }
 
+fn insert_surface_into_entities<'a>(
+    commands: &mut Commands,
+    entities: impl IntoIterator<Item = &'a Entity>,
+    name: &Name,
+    descriptor: &LevelObjectDescriptor,
+    game_assets: &GameAssets,
+) {
+    let surface = descriptor.surface.as_ref().and_then(|surface| {
+        let result = game_assets.surfaces.get(surface);
+        if result.is_none() {
+            log!("{}: unknown surface: {}", name, surface);
+        }
+        result
+    });
+    let friction = descriptor.friction.or(surface.map(|s| s.friction));
+    let restitution = descriptor.restitution.or(surface.map(|s| s.restitution));
+
+    for entity in entities {
+        if let Some(friction) = friction {
+            commands
+                .entity(*entity)
+                .try_insert(Friction::coefficient(friction));
+        }
+        if let Some(restitution) = restitution {
+            commands
+                .entity(*entity)
+                .try_insert(Restitution::coefficient(restitution));
+        }
+        if let Some(surface) = surface {
+            commands
+                .entity(*entity)
+                .try_insert(RollingDamping(surface.rolling_damping));
+        }
+    }
+}
+
 fn insert_collider_into_entities<'a>(
     commands: &mut Commands,
     entities: impl IntoIterator<Item = &'a Entity>,
@@ -376,7 +728,12 @@ fn insert_collider_into_entities<'a>(
     bundle: impl Bundle + Clone,
 ) {
     for entity in entities {
-        let Some(collider) = get_collider_from_mesh_entity(*entity, meshes, mesh_entities) else {
+        let Some(collider) = get_collider_from_mesh_entity(
+            *entity,
+            meshes,
+            mesh_entities,
+            &ComputedColliderShape::TriMesh,
+        ) else {
             continue;
         };
         commands
@@ -386,48 +743,194 @@ fn insert_collider_into_entities<'a>(
     }
 }
 
+/// Inserts convex colliders sharing the total `mass` into the entities.
+fn insert_dynamic_collider_into_entities<'a>(
+    commands: &mut Commands,
+    entities: impl IntoIterator<Item = &'a Entity>,
+    meshes: &Assets<Mesh>,
+    mesh_entities: &Query<&Handle<Mesh>>,
+    mass: f32,
+) {
+    let colliders = entities
+        .into_iter()
+        .filter_map(|entity| {
+            get_collider_from_mesh_entity(
+                *entity,
+                meshes,
+                mesh_entities,
+                &ComputedColliderShape::ConvexHull,
+            )
+            .map(|collider| (*entity, collider))
+        })
+        .collect::<Vec<_>>();
+    let mass = mass / colliders.len().max(1) as f32;
+    for (entity, collider) in colliders {
+        commands
+            .entity(entity)
+            .try_insert((collider, ColliderMassProperties::Mass(mass)));
+    }
+}
+
 fn get_collider_from_mesh_entity(
     entity: Entity,
     meshes: &Assets<Mesh>,
     mesh_entities: &Query<&Handle<Mesh>>,
+    shape: &ComputedColliderShape,
 ) -> Option<Collider> {
-    Collider::from_bevy_mesh(
-        meshes.get(mesh_entities.get(entity).ok()?)?,
-        &ComputedColliderShape::TriMesh,
-    )
+    Collider::from_bevy_mesh(meshes.get(mesh_entities.get(entity).ok()?)?, shape)
 }
 
-fn reward_points_on_collision(
+#[allow(clippy::too_many_arguments)]
+fn collect_on_collision(
     mut commands: Commands,
     game_points: Query<(Entity, &Parent, &GamePoints)>,
+    power_ups: Query<(Entity, &Parent, &GamePowerUp)>,
+    keys: Query<(Entity, &Parent, &GameKey)>,
     player: Query<Entity, With<Player>>,
-    rapier_context: ResMut<RapierContext>,
+    rapier_context: Res<RapierContext>,
     mut game_data: ResMut<GameData>,
+    mut active_power_ups: ResMut<ActivePowerUps>,
+    mut inventory: ResMut<GameInventory>,
+    children: Query<&Children>,
+    collected: Query<(), With<Collected>>,
 ) {
     let Some(player_entity) = player.iter().next() else {
         return;
     };
 
-    for (points_entity, parent_entity, game_points) in game_points.iter() {
-        if rapier_context
-            .intersection_pair(player_entity, points_entity)
-            .unwrap_or_default()
-        {
-            commands.entity(**parent_entity).despawn_recursive();
-            game_data.points += game_points.reward;
+    for (parent_entity, power_up) in
+        get_intersected_collectibles(player_entity, &power_ups, &collected, &rapier_context)
+    {
+        collect(&mut commands, &children, parent_entity);
+        match power_up.kind {
+            PowerUpKind::ExtraShot => game_data.shots += 1,
+            _ => active_power_ups.activate(power_up),
         }
     }
+
+    let multiplier = active_power_ups.points_multiplier();
+    for (parent_entity, game_points) in
+        get_intersected_collectibles(player_entity, &game_points, &collected, &rapier_context)
+    {
+        collect(&mut commands, &children, parent_entity);
+        game_data.points += game_points.reward * multiplier;
+    }
+
+    for (parent_entity, key) in
+        get_intersected_collectibles(player_entity, &keys, &collected, &rapier_context)
+    {
+        collect(&mut commands, &children, parent_entity);
+        inventory.keys.push(key.color);
+    }
+}
+
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct GameKey {
+    pub color: KeyColor,
+}
+
+/// Solid object opened by the key of the same color. Doors with an animation
+/// play it once when unlocked, other doors are collected.
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct GameDoor {
+    pub color: KeyColor,
+}
+
+/// Door opened by a collected key.
+#[derive(Component, Clone, Copy)]
+pub struct OpenedDoor;
+
+fn open_unlocked_doors(
+    mut commands: Commands,
+    mut doors: Query<(Entity, &GameDoor, Option<&mut AnimationPlayer>), Without<OpenedDoor>>,
+    inventory: Res<GameInventory>,
+    children: Query<&Children>,
+) {
+    for (entity, door, animation_player) in doors.iter_mut() {
+        if !inventory.has_key(door.color) {
+            continue;
+        }
+        commands.entity(entity).try_insert(OpenedDoor);
+        match animation_player {
+            Some(mut animation_player) => {
+                animation_player.resume();
+            }
+            None => collect(&mut commands, &children, entity),
+        }
+    }
+}
+
+fn get_intersected_collectibles<T: Component + Copy>(
+    player_entity: Entity,
+    collectibles: &Query<(Entity, &Parent, &T)>,
+    collected: &Query<(), With<Collected>>,
+    rapier_context: &RapierContext,
+) -> Vec<(Entity, T)> {
+    // disabled colliders of collected objects keep intersecting until the next physics step
+    collectibles
+        .iter()
+        .filter(|(entity, parent_entity, _)| {
+            !collected.contains(***parent_entity)
+                && rapier_context
+                    .intersection_pair(player_entity, *entity)
+                    .unwrap_or_default()
+        })
+        .map(|(_, parent_entity, collectible)| (**parent_entity, *collectible))
+        .collect()
 }
 
 #[derive(Component, Clone, Copy)]
 struct GameBounds;
 
+/// Level prop simulated by the physics engine. Props are part of the level
+/// scene, so `reset_state` restores them by respawning the scene.
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct GameDynamic {
+    pub mass: f32,
+}
+
+impl GameDynamic {
+    pub const DEFAULT_MASS: f32 = 5.0;
+}
+
+#[derive(Component, Clone, Copy)]
+struct GameCheckpoint {
+    position: Vec3,
+}
+
+fn touch_checkpoints(
+    mut collision_events: EventReader<CollisionEvent>,
+    mut respawn_point: ResMut<RespawnPoint>,
+    player: Query<Entity, With<Player>>,
+    checkpoints: Query<&GameCheckpoint>,
+) {
+    let Some(player_entity) = player.iter().next() else {
+        return;
+    };
+
+    for collision_event in collision_events.read() {
+        let Some((_, checkpoint_entity)) = player_collision_started(collision_event, player_entity)
+        else {
+            continue;
+        };
+        if let Ok(checkpoint) = checkpoints.get(checkpoint_entity) {
+            **respawn_point = Some(checkpoint.position);
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
 fn lose_on_pass_through_bounds(
+    mut commands: Commands,
     rapier_context: ResMut<RapierContext>,
     mut game_data: ResMut<GameData>,
     mut next_state: ResMut<NextState<GameState>>,
+    mut drag_info: ResMut<DragInfo>,
     player: Query<Entity, With<Player>>,
+    arrow: Query<Entity, With<ArrowScene>>,
     bounds: Query<Entity, With<GameBounds>>,
+    game_assets: Res<GameAssets>,
+    respawn_point: Res<RespawnPoint>,
     mut started: Local<bool>,
 ) {
     let Some(player_entity) = player.iter().next() else {
@@ -444,8 +947,23 @@ fn lose_on_pass_through_bounds(
                 .unwrap_or_default()
             {
                 *started = false;
-                game_data.result = Some(false);
-                next_state.set(GameState::Finished);
+                let respawn = game_data
+                    .level
+                    .and_then(|level| game_assets.levels.get(level).respawn)
+                    .zip(**respawn_point);
+                let Some((penalty, position)) = respawn else {
+                    game_data.result = Some(false);
+                    next_state.set(GameState::Finished);
+                    return;
+                };
+
+                game_data.shots = game_data.shots.saturating_sub(penalty.shots);
+                game_data.points -= penalty.points;
+                for entity in player.iter().chain(arrow.iter()) {
+                    commands.entity(entity).despawn_recursive();
+                }
+                **drag_info = None;
+                spawn_player(&mut commands, &game_assets, position);
             }
         }
         false => {
@@ -459,8 +977,165 @@ fn lose_on_pass_through_bounds(
     }
 }
 
-#[derive(Component, Clone, Copy)]
-struct GameGoal(Vec2);
+fn apply_player_damping(
+    mut player: Query<(Entity, &Submerged, &mut Damping), With<Player>>,
+    surfaces: Query<&RollingDamping>,
+    rapier_context: Res<RapierContext>,
+) {
+    let Some((player_entity, submerged, mut damping)) = player.iter_mut().next() else {
+        return;
+    };
+
+    let rolling_damping = rapier_context
+        .contact_pairs_with(player_entity)
+        .filter(|pair| pair.has_any_active_contacts())
+        .filter_map(|pair| {
+            surfaces
+                .get(pair.collider1())
+                .or_else(|_| surfaces.get(pair.collider2()))
+                .ok()
+        })
+        .map(|surface| surface.0)
+        .fold(0.0, f32::max);
+
+    let damping_value = PLAYER_DAMPING + rolling_damping + submerged.damping * submerged.fraction;
+    damping.set_if_neq(Damping {
+        linear_damping: damping_value,
+        angular_damping: damping_value,
+    });
+}
+
+fn detect_player_rest(
+    time: Res<Time>,
+    mut player: Query<(&Transform, &Velocity, &Sleeping, &mut PlayerRest)>,
+) {
+    const LINEAR_THRESHOLD: f32 = 0.05;
+    const ANGULAR_THRESHOLD: f32 = LINEAR_THRESHOLD / PLAYER_RADIUS;
+    const SETTLE_TIME: f32 = 0.5;
+
+    for (transform, velocity, sleeping, mut rest) in player.iter_mut() {
+        let still = sleeping.sleeping
+            || (velocity.linvel.length() < LINEAR_THRESHOLD
+                && velocity.angvel.length() < ANGULAR_THRESHOLD);
+        if !still {
+            if rest.resting || rest.settle_time > 0.0 {
+                rest.reset();
+            }
+            continue;
+        }
+        if rest.resting {
+            continue;
+        }
+
+        rest.settle_time += time.delta_seconds();
+        if rest.settle_time >= SETTLE_TIME {
+            rest.resting = true;
+            rest.position = transform.translation;
+        }
+    }
+}
+
+fn lose_on_rest_without_shots(
+    mut game_data: ResMut<GameData>,
+    mut next_state: ResMut<NextState<GameState>>,
+    player: Query<&PlayerRest, (With<Player>, Changed<PlayerRest>)>,
+) {
+    let Some(rest) = player.iter().next() else {
+        return;
+    };
+
+    if rest.resting && game_data.shots == 0 && game_data.result.is_none() {
+        game_data.result = Some(false);
+        next_state.set(GameState::Finished);
+    }
+}
+
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub enum GameHazard {
+    Loss,
+    Penalty(i32),
+    Respawn,
+}
+
+/// Player and the other entity of a collision starting with the player.
+pub fn player_collision_started(
+    collision_event: &CollisionEvent,
+    player_entity: Entity,
+) -> Option<(Entity, Entity)> {
+    let CollisionEvent::Started(e1, e2, _) = collision_event else {
+        return None;
+    };
+    match player_entity {
+        e if e == *e1 => Some((*e1, *e2)),
+        e if e == *e2 => Some((*e2, *e1)),
+        _ => None,
+    }
+}
+
+fn hurt_on_hazard_collision(
+    mut collision_events: EventReader<CollisionEvent>,
+    mut game_data: ResMut<GameData>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut player: Query<(Entity, &mut Transform, &mut Velocity, &mut PlayerRest), With<Player>>,
+    hazards: Query<&GameHazard>,
+) {
+    let Some((player_entity, mut transform, mut velocity, mut rest)) = player.iter_mut().next()
+    else {
+        return;
+    };
+
+    for collision_event in collision_events.read() {
+        let Some((_, hazard_entity)) = player_collision_started(collision_event, player_entity)
+        else {
+            continue;
+        };
+        let Ok(hazard) = hazards.get(hazard_entity) else {
+            continue;
+        };
+
+        match *hazard {
+            GameHazard::Loss => {
+                if game_data.result.is_none() {
+                    game_data.result = Some(false);
+                    next_state.set(GameState::Finished);
+                }
+            }
+            GameHazard::Penalty(points) => game_data.points -= points,
+            GameHazard::Respawn => {
+                transform.translation = rest.position;
+                *velocity = Velocity::zero();
+                rest.reset();
+            }
+        }
+    }
+}
+
+/// Pass-through normal of a goal, the player wins by crossing the goal plane
+/// in the normal direction.
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub enum GameGoal {
+    World(Vec3),
+    /// Relative to the goal rotation.
+    Local(Vec3),
+}
+
+impl GameGoal {
+    fn normal(&self, transform: &GlobalTransform) -> Vec3 {
+        match *self {
+            Self::World(normal) => normal,
+            Self::Local(normal) => transform.compute_transform().rotation * normal,
+        }
+    }
+
+    /// Signed distance of the point from the goal plane.
+    fn signed_distance(&self, transform: &GlobalTransform, point: Vec3) -> f32 {
+        (point - transform.translation()).dot(self.normal(transform))
+    }
+
+    fn is_crossed(entry_distance: f32, exit_distance: f32) -> bool {
+        entry_distance <= 0.0 && exit_distance > 0.0
+    }
+}
 
 fn win_on_pass_through_goal(
     mut game_data: ResMut<GameData>,
@@ -468,7 +1143,7 @@ fn win_on_pass_through_goal(
     player: Query<(Entity, &Transform), With<Player>>,
     goals: Query<(Entity, &GlobalTransform, &GameGoal)>,
     rapier_context: Res<RapierContext>,
-    mut started: Local<Option<Entity>>,
+    mut started: Local<Option<(Entity, f32)>>,
 ) {
     let Some((player_entity, player_transform)) = player.iter().next() else {
         return;
@@ -477,26 +1152,24 @@ fn win_on_pass_through_goal(
     let mut is_intersected = false;
 
     for (e1, e2, _overlap) in rapier_context.intersection_pairs_with(player_entity) {
-        let Some(goal_entity) = goals
-            .get(e1)
-            .ok()
-            .or_else(|| goals.get(e2).ok())
-            .map(|(e, _, _)| e)
+        let Some((goal_entity, goal_transform, goal)) =
+            goals.get(e1).ok().or_else(|| goals.get(e2).ok())
         else {
             continue;
         };
         is_intersected = true;
-        if Some(goal_entity) == *started {
+        if started.is_some_and(|(e, _)| e == goal_entity) {
             continue;
         }
-        *started = Some(goal_entity);
+        let entry_distance = goal.signed_distance(goal_transform, player_transform.translation);
+        *started = Some((goal_entity, entry_distance));
     }
 
     if is_intersected {
         return;
     }
 
-    let Some(goal_entity) = *started else {
+    let Some((goal_entity, entry_distance)) = started.take() else {
         return;
     };
 
@@ -504,27 +1177,37 @@ fn win_on_pass_through_goal(
         return;
     };
 
-    let movement =
-        (player_transform.translation.xy() - goal_transform.translation().xy()).normalize();
-    if movement.x * goal.0.x >= 0.0 && movement.y * goal.0.y >= 0.0 {
+    let exit_distance = goal.signed_distance(goal_transform, player_transform.translation);
+    if GameGoal::is_crossed(entry_distance, exit_distance) {
         game_data.result = Some(true);
         next_state.set(GameState::Finished);
     }
-    *started = None;
 }
 
 fn pause_animation_players(
-    mut game_scene_animations: Query<&mut AnimationPlayer, With<GameSceneAnimationPlayer>>,
+    mut commands: Commands,
+    mut game_scene_animations: Query<
+        (Entity, &mut AnimationPlayer),
+        With<GameSceneAnimationPlayer>,
+    >,
 ) {
-    for mut player in game_scene_animations.iter_mut() {
+    for (entity, mut player) in game_scene_animations.iter_mut() {
+        commands
+            .entity(entity)
+            .try_insert(PausedSpeed(player.speed()));
         player.set_speed(0.0);
     }
 }
 
 fn resume_animation_players(
-    mut game_scene_animations: Query<&mut AnimationPlayer, With<GameSceneAnimationPlayer>>,
+    mut commands: Commands,
+    mut game_scene_animations: Query<
+        (Entity, &mut AnimationPlayer, &PausedSpeed),
+        With<GameSceneAnimationPlayer>,
+    >,
 ) {
-    for mut player in game_scene_animations.iter_mut() {
-        player.set_speed(1.0);
+    for (entity, mut player, paused_speed) in game_scene_animations.iter_mut() {
+        player.set_speed(paused_speed.0);
+        commands.entity(entity).remove::<PausedSpeed>();
     }
 }
@@ -1,6 +1,9 @@
 use super::{
-    aiming_plugin::DragInfo,
+    aiming_plugin::{AimSource, BallReady, DragInfo},
     game_scene_plugin::{GameData, SetGameLevel},
+    power_up_plugin::ActivePowerUps,
+    replay_plugin::ShotReplay,
+    rewind_plugin::{PracticeMode, RewindShot, ShotHistory},
 };
 use crate::{
     common::plugins::ui_plugin::{
@@ -9,7 +12,8 @@ use crate::{
     },
     game::game_plugin::GameState,
     resources::{
-        game_assets::{GameColor, GameImage},
+        game_assets::{GameAssets, GameColor, GameImage, GameLevel},
+        save_data::SaveData,
         text_styles::{FontSize, FontType},
     },
     utils::rotate_point,
@@ -29,6 +33,7 @@ impl Plugin for GameUiPlugin {
                     switch_ui.run_if(state_changed::<GameState>),
                     (
                         update_score_tracker.run_if(resource_changed::<GameData>),
+                        update_power_up_tracker.run_if(resource_changed::<ActivePowerUps>),
                         set_aim_circle_visibility.before(render_ui),
                     )
                         .run_if(in_state(GameState::Playing))
@@ -50,23 +55,43 @@ fn cleanup(
     });
 }
 
+#[allow(clippy::too_many_arguments)]
 fn switch_ui(
     mut commands: Commands,
     mut game_data: ResMut<GameData>,
     game_state: Res<State<GameState>>,
     playing: Query<Entity, With<PlayingElement>>,
     paused: Query<Entity, With<PausedElement>>,
+    shot_history: Res<ShotHistory>,
+    practice: Res<PracticeMode>,
+    replay: Res<ShotReplay>,
+    save_data: Res<SaveData>,
     mut ui_builder: UiBuilder,
 ) {
     cleanup(commands.reborrow(), playing, paused);
+    let can_rewind = !replay.is_active()
+        && game_data.level.is_some_and(|level| {
+            shot_history.can_rewind(practice.rewind_limit(ui_builder.game_assets.levels.get(level)))
+        });
     match **game_state {
-        GameState::Paused => spawn_pause_menu(&mut commands, &mut ui_builder),
+        GameState::Paused => spawn_pause_menu(&mut commands, &mut ui_builder, can_rewind),
         GameState::Playing => spawn_game_menu(&mut commands, &mut ui_builder, &mut game_data),
         GameState::Finished => {
             if game_data.result.unwrap() {
-                spawn_win_screen(&mut commands, &mut ui_builder, &game_data);
+                let levels = &ui_builder.game_assets.levels;
+                let next_level = game_data.level.and_then(|level| levels.next(level));
+                // practice runs do not unlock the next level
+                let next_unlocked =
+                    next_level.is_some_and(|level| save_data.is_unlocked(levels, level));
+                spawn_win_screen(
+                    &mut commands,
+                    &mut ui_builder,
+                    &game_data,
+                    next_level,
+                    next_unlocked,
+                );
             } else {
-                spawn_lose_screen(&mut commands, &mut ui_builder);
+                spawn_lose_screen(&mut commands, &mut ui_builder, can_rewind);
             }
         }
     }
@@ -76,6 +101,8 @@ trait GameUiOnClick {
     fn pause_game() -> Self;
     fn resume_game() -> Self;
     fn restart_game() -> Self;
+    fn rewind_shot() -> Self;
+    fn next_level() -> Self;
     fn back_to_main_menu() -> Self;
     fn start_aim() -> Self;
 }
@@ -83,6 +110,9 @@ trait GameUiOnClick {
 impl GameUiOnClick for UiOnClick {
     fn pause_game() -> Self {
         Self::new(|w, _| {
+            if w.resource::<ShotReplay>().is_active() {
+                return;
+            }
             w.resource_mut::<NextState<GameState>>()
                 .set(GameState::Paused);
         })
@@ -103,6 +133,31 @@ impl GameUiOnClick for UiOnClick {
         })
     }
 
+    fn rewind_shot() -> Self {
+        Self::new(|w, _| {
+            w.resource_mut::<NextState<GameState>>()
+                .set(GameState::Playing);
+            w.send_event(RewindShot);
+        })
+    }
+
+    fn next_level() -> Self {
+        Self::new(|w, _| {
+            let levels = &w.resource::<GameAssets>().levels;
+            let Some(next_level) = w
+                .resource::<GameData>()
+                .level
+                .and_then(|level| levels.next(level))
+                .filter(|level| w.resource::<SaveData>().is_unlocked(levels, *level))
+            else {
+                return;
+            };
+            w.resource_mut::<NextState<GameState>>()
+                .set(GameState::Playing);
+            w.send_event(SetGameLevel(Some(next_level)));
+        })
+    }
+
     fn back_to_main_menu() -> Self {
         Self::new(|w, _| {
             w.resource_mut::<NextState<AppState>>()
@@ -116,18 +171,28 @@ impl GameUiOnClick for UiOnClick {
                 return;
             };
             let game_data = w.resource::<GameData>();
-            if game_data.shots == 0 {
+            if game_data.shots == 0
+                || !**w.resource::<BallReady>()
+                || w.resource::<ShotReplay>().is_active()
+            {
                 return;
             }
+            let source = match w
+                .resource::<Touches>()
+                .get_pressed(*pointer_data.user_input)
+            {
+                Some(_) => AimSource::Touch,
+                None => AimSource::Mouse,
+            };
             let mut drag_info = w.resource_mut::<DragInfo>();
-            drag_info.start(pointer_data.pos, pointer_data.user_input);
+            drag_info.start(pointer_data.pos, pointer_data.user_input, source);
         });
         res.eager_handle = true;
         res
     }
 }
 
-fn spawn_lose_screen(commands: &mut Commands, ui_builder: &mut UiBuilder) {
+fn spawn_lose_screen(commands: &mut Commands, ui_builder: &mut UiBuilder, can_rewind: bool) {
     let base = UiBase::new(Color::rgba(0.0, 0.0, 0.0, 0.5));
     let container: UiContainer = ui_builder
         .create::<UiContainer>(Val::Auto, Val::Auto)
@@ -145,7 +210,17 @@ fn spawn_lose_screen(commands: &mut Commands, ui_builder: &mut UiBuilder) {
                     .get_content(GameColor::Warning),
             ),
         );
-    let buttons = vec![
+    let mut buttons = Vec::new();
+    if can_rewind {
+        buttons.push(
+            ui_builder
+                .create::<UiButton>(Val::Auto, Val::Auto)
+                .with_text("Rewind shot")
+                .with_on_click(UiOnClick::rewind_shot())
+                .with_game_color(GameColor::Warning, ui_builder),
+        );
+    }
+    buttons.extend([
         ui_builder
             .create::<UiButton>(Val::Auto, Val::Auto)
             .with_text("Retry")
@@ -156,7 +231,7 @@ fn spawn_lose_screen(commands: &mut Commands, ui_builder: &mut UiBuilder) {
             .with_text("Back to main menu")
             .with_on_click(UiOnClick::back_to_main_menu())
             .with_game_color(GameColor::Neutral, ui_builder),
-    ];
+    ]);
 
     base.spawn(commands)
         .insert(PlayingElement)
@@ -170,14 +245,23 @@ fn spawn_lose_screen(commands: &mut Commands, ui_builder: &mut UiBuilder) {
         });
 }
 
-fn spawn_win_screen(commands: &mut Commands, ui_builder: &mut UiBuilder, game_data: &GameData) {
+fn spawn_win_screen(
+    commands: &mut Commands,
+    ui_builder: &mut UiBuilder,
+    game_data: &GameData,
+    next_level: Option<GameLevel>,
+    next_unlocked: bool,
+) {
     let base = UiBase::new(Color::rgba(0.0, 0.0, 0.0, 0.5));
     let container = ui_builder
         .create::<UiContainer>(Val::Auto, Val::Auto)
         .with_game_color(GameColor::Success, ui_builder);
     let win_text = ui_builder
         .create_auto::<UiText>()
-        .with_text("You won!")
+        .with_text(match next_level {
+            Some(_) => "You won!",
+            None => "All levels complete!",
+        })
         .with_text_style(
             ui_builder.text_styles.get(
                 FontType::Bold,
@@ -219,11 +303,16 @@ fn spawn_win_screen(commands: &mut Commands, ui_builder: &mut UiBuilder, game_da
                 .with_count(1),
         );
     }
-    let buttons = vec![
-        ui_builder
-            .create::<UiButton>(Val::Auto, Val::Auto)
-            .with_text("Next level")
-            .with_on_click(UiOnClick::restart_game()), // TODO: change when levels are implemented
+    let mut buttons = Vec::new();
+    if next_unlocked {
+        buttons.push(
+            ui_builder
+                .create::<UiButton>(Val::Auto, Val::Auto)
+                .with_text("Next level")
+                .with_on_click(UiOnClick::next_level()),
+        );
+    }
+    buttons.extend([
         ui_builder
             .create::<UiButton>(Val::Auto, Val::Auto)
             .with_text("Retry")
@@ -233,15 +322,12 @@ fn spawn_win_screen(commands: &mut Commands, ui_builder: &mut UiBuilder, game_da
             .with_text("Back to main menu")
             .with_on_click(UiOnClick::back_to_main_menu())
             .with_game_color(GameColor::Neutral, ui_builder),
-    ];
-    let star_index = game_data
-        .level
-        .unwrap()
-        .get_meta()
-        .star_point_thresholds
-        .into_iter()
-        .position(|threshold| game_data.points < threshold as i32)
-        .unwrap_or(3);
+    ]);
+    let star_index = ui_builder
+        .game_assets
+        .levels
+        .get(game_data.level.unwrap())
+        .get_stars(game_data.points) as usize;
 
     base.spawn(commands)
         .insert(PlayingElement)
@@ -261,7 +347,7 @@ fn spawn_win_screen(commands: &mut Commands, ui_builder: &mut UiBuilder, game_da
         });
 }
 
-fn spawn_pause_menu(commands: &mut Commands, ui_builder: &mut UiBuilder) {
+fn spawn_pause_menu(commands: &mut Commands, ui_builder: &mut UiBuilder, can_rewind: bool) {
     let base = UiBase::new(Color::rgba(0.0, 0.0, 0.0, 0.5));
     let menu: UiContainer = ui_builder.create(Val::Auto, Val::Auto);
     let text = ui_builder
@@ -277,11 +363,19 @@ fn spawn_pause_menu(commands: &mut Commands, ui_builder: &mut UiBuilder) {
                     .get_content(GameColor::Warning),
             ),
         );
-    let buttons = vec![
-        ui_builder
-            .create::<UiButton>(Val::Auto, Val::Auto)
-            .with_text("Resume")
-            .with_on_click(UiOnClick::resume_game()),
+    let mut buttons = vec![ui_builder
+        .create::<UiButton>(Val::Auto, Val::Auto)
+        .with_text("Resume")
+        .with_on_click(UiOnClick::resume_game())];
+    if can_rewind {
+        buttons.push(
+            ui_builder
+                .create::<UiButton>(Val::Auto, Val::Auto)
+                .with_text("Rewind shot")
+                .with_on_click(UiOnClick::rewind_shot()),
+        );
+    }
+    buttons.extend([
         ui_builder
             .create::<UiButton>(Val::Auto, Val::Auto)
             .with_text("Restart")
@@ -290,7 +384,7 @@ fn spawn_pause_menu(commands: &mut Commands, ui_builder: &mut UiBuilder) {
             .create::<UiButton>(Val::Auto, Val::Auto)
             .with_text("Back to main menu")
             .with_on_click(UiOnClick::back_to_main_menu()),
-    ];
+    ]);
 
     base.spawn(commands)
         .insert(PausedElement)
@@ -318,6 +412,7 @@ fn spawn_game_menu(
     spawn_aim_circle(commands, ui_builder);
     spawn_pause_button(commands, ui_builder);
     spawn_score_tracker(commands, ui_builder, game_data);
+    spawn_power_up_tracker(commands, ui_builder);
 }
 
 #[derive(Component)]
@@ -326,10 +421,24 @@ pub struct AimCircle;
 #[derive(Component)]
 pub struct ShotsTracker;
 
+#[derive(Component)]
+struct AimCircleButton;
+
+const AIM_CIRCLE_READY: u64 = 0;
+const AIM_CIRCLE_WAITING: u64 = 1;
+
+type AimCircleButtonBundle = (
+    NodeBundle,
+    UiOnClickBundle,
+    UiNode,
+    UiState,
+    AimCircleButton,
+);
+
 struct ShotsComponent {
     pub inner_ratio: f32,
     shots: Box<dyn Fn(f32) -> (NodeBundle, AimCircle, UiNode, UiState, ShotsTracker)>,
-    circle: Box<dyn Fn(f32, f32) -> (NodeBundle, UiOnClickBundle, UiNode)>,
+    circle: Box<dyn Fn(f32, f32) -> AimCircleButtonBundle>,
 }
 
 impl UiComponent for ShotsComponent {
@@ -393,9 +502,13 @@ impl UiComponent for ShotsComponent {
                         ..Default::default()
                     },
                     UiNode {
-                        paint: Box::new(|painter, size, _, _| {
+                        paint: Box::new(|painter, size, _, state| {
                             let radius = size.x.min(size.y) / 2.0;
-                            painter.color = Color::WHITE.with_a(0.9);
+                            let (alpha, dash) = match state {
+                                AIM_CIRCLE_WAITING => (0.35, 6.0),
+                                _ => (0.9, 2.7),
+                            };
+                            painter.color = Color::WHITE.with_a(alpha);
                             painter.hollow = true;
                             painter.thickness = 0.8;
                             painter.thickness_type = ThicknessType::Screen;
@@ -404,12 +517,14 @@ impl UiComponent for ShotsComponent {
                                 const STEP: f32 = 1.0 / STEPS as f32 * 2.0 * std::f32::consts::PI;
                                 const OFFSET: f32 = 0.3 * STEP;
                                 let angle = i as f32 * STEP + OFFSET;
-                                painter.arc(radius, angle, angle + STEP / 2.7);
+                                painter.arc(radius, angle, angle + STEP / dash);
                             }
                         }),
                         corner_radius: radius,
                         z,
                     },
+                    UiState(AIM_CIRCLE_WAITING),
+                    AimCircleButton,
                 )
             }),
         }
@@ -431,14 +546,25 @@ pub fn spawn_aim_circle(commands: &mut Commands, ui_builder: &mut UiBuilder) {
 
 fn set_aim_circle_visibility(
     mut circle: Query<&mut Style, With<AimCircle>>,
+    mut circle_button: Query<&mut UiState, With<AimCircleButton>>,
     game_data: Res<GameData>,
     drag_info: Res<DragInfo>,
+    ball_ready: Res<BallReady>,
     game_state: Res<State<GameState>>,
 ) {
-    if !game_data.is_changed() && !drag_info.is_changed() && !game_state.is_changed() {
+    if !game_data.is_changed()
+        && !drag_info.is_changed()
+        && !ball_ready.is_changed()
+        && !game_state.is_changed()
+    {
         return;
     }
 
+    circle_button.single_mut().0 = match **ball_ready {
+        true => AIM_CIRCLE_READY,
+        false => AIM_CIRCLE_WAITING,
+    };
+
     let visible =
         game_data.shots > 0 && drag_info.is_none() && game_state.get() == &GameState::Playing;
     circle.single_mut().display = match visible {
@@ -462,13 +588,14 @@ fn update_score_tracker(
     mut progress_state: Query<&mut UiState, With<ProgressStars>>,
     mut shots_state: Query<&mut UiState, (With<ShotsTracker>, Without<ProgressStars>)>,
     game_data: Res<GameData>,
+    game_assets: Res<GameAssets>,
 ) {
     score.single_mut().sections[0].value = game_data.points.to_string();
     let Some(level) = game_data.level else {
         return;
     };
     let mut progress_state = progress_state.single_mut();
-    let meta = level.get_meta();
+    let meta = game_assets.levels.get(level);
     let thresholds = [
         (meta.star_point_thresholds[0] as i32, 0.35),
         (meta.star_point_thresholds[1] as i32, 0.65),
@@ -702,6 +829,46 @@ fn spawn_score_tracker(
         });
 }
 
+#[derive(Component)]
+struct PowerUpTracker;
+
+fn spawn_power_up_tracker(commands: &mut Commands, ui_builder: &mut UiBuilder) {
+    let window = ui_builder.window();
+    let offset = window.height().min(window.width()) / 60.0;
+    let mut base = UiBase::new(Color::rgba(0.0, 0.0, 0.0, 0.0));
+    base.style.align_items = AlignItems::Start;
+    base.style.justify_content = JustifyContent::End;
+    base.style.padding = UiRect::all(Val::Px(offset));
+    let text = ui_builder
+        .create_auto::<UiText>()
+        .with_text_style(ui_builder.text_styles.get(
+            FontType::Bold,
+            FontSize::Small,
+            ui_builder.game_assets.colors.get(GameColor::Accent),
+        ));
+
+    base.spawn(commands)
+        .insert(PlayingElement)
+        .with_children(|parent| {
+            text.spawn(parent).insert(PowerUpTracker);
+        });
+}
+
+fn update_power_up_tracker(
+    mut tracker: Query<&mut Text, With<PowerUpTracker>>,
+    active_power_ups: Res<ActivePowerUps>,
+) {
+    let Some(mut tracker) = tracker.iter_mut().next() else {
+        return;
+    };
+
+    tracker.sections[0].value = active_power_ups
+        .iter()
+        .map(|p| format!("{} {:.0}s", p.kind.name(), p.timer.remaining_secs().ceil()))
+        .collect::<Vec<_>>()
+        .join("\n");
+}
+
 fn spawn_pause_button(commands: &mut Commands, ui_builder: &mut UiBuilder) {
     let window = ui_builder.window();
     let a = window.height().min(window.width()) / 6.0;
@@ -0,0 +1,143 @@
+use super::game_scene_plugin::GameEntity;
+use crate::{log, AppState};
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::*;
+use serde_derive::Deserialize;
+
+pub struct JointPlugin;
+
+impl Plugin for JointPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, build_joints.run_if(in_state(AppState::InGame)));
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+pub enum JointKind {
+    Revolute,
+    Prismatic,
+    Fixed,
+}
+
+/// Joint attaching a dynamic object at its origin to the `target` object, or
+/// to the world without a target. Joint objects are expected to be top level
+/// nodes of the level scene.
+#[derive(Component, Clone, Debug, PartialEq)]
+pub struct GameJoint {
+    pub kind: JointKind,
+    pub target: Option<String>,
+    /// Rotation or translation axis in the object local space.
+    pub axis: Vec3,
+    /// Angle limits in radians, or translation limits in meters.
+    pub limits: Option<[f32; 2]>,
+    /// Target velocity of the joint motor, e.g. for spinners.
+    pub motor: Option<f32>,
+}
+
+impl GameJoint {
+    fn build(&self, object: &GlobalTransform, target: &GlobalTransform) -> GenericJoint {
+        const MOTOR_FACTOR: f32 = 10.0;
+
+        // rigid body frames have no scale
+        let (_, object_rotation, object_translation) = object.to_scale_rotation_translation();
+        let (_, target_rotation, target_translation) = target.to_scale_rotation_translation();
+        let basis = target_rotation.inverse() * object_rotation;
+        let anchor = target_rotation.inverse() * (object_translation - target_translation);
+
+        let mut joint: GenericJoint = match self.kind {
+            JointKind::Revolute => {
+                let mut joint = RevoluteJointBuilder::new(self.axis);
+                if let Some(limits) = self.limits {
+                    joint = joint.limits(limits);
+                }
+                if let Some(motor) = self.motor {
+                    joint = joint.motor_velocity(motor, MOTOR_FACTOR);
+                }
+                joint.into()
+            }
+            JointKind::Prismatic => {
+                let mut joint = PrismaticJointBuilder::new(self.axis);
+                if let Some(limits) = self.limits {
+                    joint = joint.limits(limits);
+                }
+                if let Some(motor) = self.motor {
+                    joint = joint.motor_velocity(motor, MOTOR_FACTOR);
+                }
+                joint.into()
+            }
+            JointKind::Fixed => FixedJointBuilder::new().into(),
+        };
+        match self.kind {
+            JointKind::Fixed => joint.set_local_basis1(basis),
+            _ => joint.set_local_axis1(basis * self.axis),
+        };
+        joint.set_local_anchor1(anchor);
+        joint
+    }
+}
+
+fn build_joints(
+    mut commands: Commands,
+    joints: Query<(Entity, &Name, &GlobalTransform, &GameJoint), Added<GameJoint>>,
+    objects: Query<(Entity, &Name, &GlobalTransform, Has<RigidBody>)>,
+) {
+    for (entity, name, transform, joint) in joints.iter() {
+        let target = joint.target.as_ref().and_then(|target| {
+            let result = objects
+                .iter()
+                .find(|(_, name, _, _)| name.as_str() == target);
+            if result.is_none() {
+                log!("{}: unknown joint target: {}", name, target);
+            }
+            result
+        });
+
+        let (target_entity, target_transform) = match target {
+            Some((target_entity, _, target_transform, has_body)) => {
+                if !has_body {
+                    commands.entity(target_entity).try_insert(RigidBody::Fixed);
+                }
+                (target_entity, *target_transform)
+            }
+            None => {
+                let anchor = commands
+                    .spawn((RigidBody::Fixed, TransformBundle::default(), GameEntity))
+                    .id();
+                (anchor, GlobalTransform::IDENTITY)
+            }
+        };
+
+        commands.entity(entity).try_insert(ImpulseJoint::new(
+            target_entity,
+            joint.build(transform, &target_transform),
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_anchor_ignores_target_scale() {
+        let joint = GameJoint {
+            kind: JointKind::Revolute,
+            target: Some("Object_Base".to_string()),
+            axis: Vec3::X,
+            limits: None,
+            motor: None,
+        };
+        let object = GlobalTransform::from_translation(Vec3::new(1.0, 2.0, 0.0));
+        let target = GlobalTransform::from(
+            Transform::from_xyz(1.0, 0.0, 0.0)
+                .with_rotation(Quat::from_rotation_z(std::f32::consts::FRAC_PI_2))
+                .with_scale(Vec3::splat(2.0)),
+        );
+
+        let built = joint.build(&object, &target);
+        assert!(built
+            .local_anchor1()
+            .abs_diff_eq(Vec3::new(2.0, 0.0, 0.0), 1e-5));
+        assert!(built.local_axis1().abs_diff_eq(-Vec3::Y, 1e-5));
+    }
+}
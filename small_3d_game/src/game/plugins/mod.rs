@@ -1,5 +1,14 @@
 pub mod aiming_plugin;
+pub mod breakable_plugin;
 pub mod custom_tweening_plugin;
+pub mod force_field_plugin;
 pub mod game_camera_plugin;
 pub mod game_scene_plugin;
 pub mod game_ui_plugin;
+pub mod joint_plugin;
+pub mod pad_plugin;
+pub mod portal_plugin;
+pub mod power_up_plugin;
+pub mod replay_plugin;
+pub mod rewind_plugin;
+pub mod switch_plugin;
@@ -0,0 +1,155 @@
+use super::game_scene_plugin::{player_collision_started, Player};
+use crate::{
+    game::game_plugin::{GameState, PhysicsSchedule, PHYSICS_TIMESTEP},
+    AppState,
+};
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::*;
+
+pub struct PadPlugin;
+
+impl Plugin for PadPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (boost_on_collision, bounce_on_collision)
+                .run_if(in_state(AppState::InGame))
+                .run_if(in_state(GameState::Playing)),
+        )
+        .add_systems(
+            PhysicsSchedule,
+            drag_on_conveyors.before(PhysicsSet::SyncBackend),
+        );
+    }
+}
+
+/// Sensor pushing the player along its local forward (Y) axis.
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct GameBooster {
+    /// Impulse applied to the player.
+    pub strength: f32,
+}
+
+impl GameBooster {
+    pub const DEFAULT_STRENGTH: f32 = 100.0;
+}
+
+/// Solid object reflecting the player away from its surface.
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct GameBouncer {
+    /// Minimal speed of the player leaving the surface.
+    pub strength: f32,
+}
+
+impl GameBouncer {
+    pub const DEFAULT_STRENGTH: f32 = 5.0;
+
+    pub fn restitution() -> Restitution {
+        Restitution {
+            coefficient: 1.0,
+            combine_rule: CoefficientCombineRule::Max,
+        }
+    }
+}
+
+/// Solid surface dragging touching bodies along its local forward (Y) axis.
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct GameConveyor {
+    pub speed: f32,
+}
+
+impl GameConveyor {
+    pub const DEFAULT_SPEED: f32 = 2.0;
+}
+
+fn boost_on_collision(
+    mut collision_events: EventReader<CollisionEvent>,
+    mut player: Query<(Entity, &mut ExternalImpulse), With<Player>>,
+    boosters: Query<(&GlobalTransform, &GameBooster)>,
+) {
+    let Some((player_entity, mut impulse)) = player.iter_mut().next() else {
+        return;
+    };
+
+    for collision_event in collision_events.read() {
+        let Some((_, booster_entity)) = player_collision_started(collision_event, player_entity)
+        else {
+            continue;
+        };
+        let Ok((transform, booster)) = boosters.get(booster_entity) else {
+            continue;
+        };
+
+        let forward = transform.compute_transform().rotation * Vec3::Y;
+        impulse.impulse += forward * booster.strength;
+    }
+}
+
+fn bounce_on_collision(
+    mut collision_events: EventReader<CollisionEvent>,
+    mut player: Query<(Entity, &mut Velocity), With<Player>>,
+    bouncers: Query<&GameBouncer>,
+    rapier_context: Res<RapierContext>,
+) {
+    let Some((player_entity, mut velocity)) = player.iter_mut().next() else {
+        return;
+    };
+
+    for collision_event in collision_events.read() {
+        let Some((_, bouncer_entity)) = player_collision_started(collision_event, player_entity)
+        else {
+            continue;
+        };
+        let Ok(bouncer) = bouncers.get(bouncer_entity) else {
+            continue;
+        };
+        let Some(contact_pair) = rapier_context.contact_pair(player_entity, bouncer_entity) else {
+            continue;
+        };
+        let Some(manifold) = contact_pair.manifolds().next() else {
+            continue;
+        };
+
+        // the manifold normal points from the first to the second collider
+        let normal = match contact_pair.collider1() == player_entity {
+            true => -manifold.normal(),
+            false => manifold.normal(),
+        };
+        let speed = velocity.linvel.dot(normal);
+        if speed < bouncer.strength {
+            velocity.linvel += normal * (bouncer.strength - speed);
+        }
+    }
+}
+
+fn drag_on_conveyors(
+    conveyors: Query<(Entity, &GlobalTransform, &GameConveyor)>,
+    mut velocities: Query<&mut Velocity>,
+    rapier_context: Res<RapierContext>,
+) {
+    // share of the velocity difference removed each step
+    const GRIP: f32 = 10.0 * PHYSICS_TIMESTEP;
+
+    for (conveyor_entity, transform, conveyor) in conveyors.iter() {
+        let belt = transform.compute_transform().rotation * Vec3::Y * conveyor.speed;
+        for contact_pair in rapier_context.contact_pairs_with(conveyor_entity) {
+            if !contact_pair.has_any_active_contacts() {
+                continue;
+            }
+            let Some(manifold) = contact_pair.manifolds().next() else {
+                continue;
+            };
+            let body = match contact_pair.collider1() == conveyor_entity {
+                true => manifold.rigid_body2(),
+                false => manifold.rigid_body1(),
+            };
+            let Some(mut velocity) = body.and_then(|body| velocities.get_mut(body).ok()) else {
+                continue;
+            };
+
+            let normal = manifold.normal();
+            let slip = velocity.linvel - belt;
+            velocity.linvel -= (slip - normal * slip.dot(normal)) * GRIP;
+        }
+    }
+}
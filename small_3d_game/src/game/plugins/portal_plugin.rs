@@ -0,0 +1,128 @@
+use super::game_scene_plugin::Player;
+use crate::{game::game_plugin::GameState, AppState};
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::*;
+use serde_derive::Deserialize;
+use std::f32::consts::PI;
+
+pub struct PortalPlugin;
+
+impl Plugin for PortalPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            teleport_through_portals
+                .run_if(in_state(AppState::InGame))
+                .run_if(in_state(GameState::Playing)),
+        );
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+pub enum PortalSide {
+    A,
+    B,
+}
+
+impl PortalSide {
+    pub fn other(self) -> Self {
+        match self {
+            Self::A => Self::B,
+            Self::B => Self::A,
+        }
+    }
+}
+
+/// Sensor teleporting the player to the portal with the same id and the
+/// other side. The player enters and leaves portals along their local
+/// forward (Y) axis.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GamePortal {
+    pub id: u32,
+    pub side: PortalSide,
+}
+
+impl GamePortal {
+    fn partner(&self) -> Self {
+        Self {
+            id: self.id,
+            side: self.side.other(),
+        }
+    }
+
+    /// Rotation turning the entry direction of `entry` into the exit
+    /// direction of `exit`.
+    fn transfer_rotation(entry: &GlobalTransform, exit: &GlobalTransform) -> Quat {
+        let entry_rotation = entry.compute_transform().rotation;
+        let exit_rotation = exit.compute_transform().rotation;
+        exit_rotation * Quat::from_rotation_z(PI) * entry_rotation.inverse()
+    }
+}
+
+struct PortalCooldown {
+    timer: Timer,
+    /// Exit portal the player has not left yet.
+    exit: Option<Entity>,
+}
+
+impl Default for PortalCooldown {
+    fn default() -> Self {
+        let mut timer = Timer::from_seconds(0.5, TimerMode::Once);
+        timer.tick(timer.duration());
+        Self { timer, exit: None }
+    }
+}
+
+fn teleport_through_portals(
+    time: Res<Time>,
+    mut player: Query<(Entity, &mut Transform, &mut Velocity), With<Player>>,
+    portals: Query<(Entity, &GlobalTransform, &GamePortal)>,
+    rapier_context: Res<RapierContext>,
+    mut cooldown: Local<PortalCooldown>,
+) {
+    cooldown.timer.tick(time.delta());
+
+    let Some((player_entity, mut transform, mut velocity)) = player.iter_mut().next() else {
+        return;
+    };
+
+    let intersected = rapier_context
+        .intersection_pairs_with(player_entity)
+        .filter(|(_, _, intersecting)| *intersecting)
+        .filter_map(|(e1, e2, _)| portals.get(e1).or_else(|_| portals.get(e2)).ok())
+        .collect::<Vec<_>>();
+
+    if let Some(exit) = cooldown.exit {
+        if intersected.iter().all(|(e, _, _)| *e != exit) {
+            cooldown.exit = None;
+        }
+    }
+
+    if !cooldown.timer.finished() {
+        return;
+    }
+
+    for (entry_entity, entry_transform, portal) in intersected {
+        if Some(entry_entity) == cooldown.exit {
+            continue;
+        }
+        let partner = portal.partner();
+        let Some((exit_entity, exit_transform, _)) =
+            portals.iter().find(|(_, _, p)| **p == partner)
+        else {
+            continue;
+        };
+
+        let rotation = GamePortal::transfer_rotation(entry_transform, exit_transform);
+        let exit_forward = exit_transform.compute_transform().rotation * Vec3::Y;
+        let offset = rotation * (transform.translation - entry_transform.translation());
+        transform.translation =
+            exit_transform.translation() + offset - exit_forward * offset.dot(exit_forward);
+        velocity.linvel = rotation * velocity.linvel;
+        velocity.angvel = rotation * velocity.angvel;
+
+        cooldown.timer.reset();
+        cooldown.exit = Some(exit_entity);
+        break;
+    }
+}
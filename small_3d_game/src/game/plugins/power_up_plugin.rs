@@ -0,0 +1,129 @@
+use super::game_scene_plugin::{Player, PLAYER_RESTITUTION};
+use crate::game::game_plugin::{PhysicsSchedule, PHYSICS_TIMESTEP};
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::*;
+use serde_derive::Deserialize;
+use std::time::Duration;
+
+pub struct PowerUpPlugin;
+
+impl Plugin for PowerUpPlugin {
+    fn build(&self, app: &mut App) {
+        // power ups expire on the same physics step in replays
+        app.init_resource::<ActivePowerUps>().add_systems(
+            PhysicsSchedule,
+            (tick_power_ups, apply_power_up_effects)
+                .chain()
+                .before(PhysicsSet::SyncBackend),
+        );
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+pub enum PowerUpKind {
+    ExtraShot,
+    DoublePoints,
+    LowGravity,
+    Sticky,
+}
+
+impl PowerUpKind {
+    pub fn name(&self) -> &str {
+        match self {
+            Self::ExtraShot => "Extra shot",
+            Self::DoublePoints => "Double points",
+            Self::LowGravity => "Low gravity",
+            Self::Sticky => "Sticky ball",
+        }
+    }
+}
+
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct GamePowerUp {
+    pub kind: PowerUpKind,
+    /// Duration of the effect in seconds, ignored by instant power ups.
+    pub duration: f32,
+}
+
+impl GamePowerUp {
+    pub const DEFAULT_DURATION: f32 = 10.0;
+}
+
+#[derive(Clone)]
+pub struct ActivePowerUp {
+    pub kind: PowerUpKind,
+    pub timer: Timer,
+}
+
+#[derive(Resource, Default, Clone)]
+pub struct ActivePowerUps(Vec<ActivePowerUp>);
+
+impl ActivePowerUps {
+    pub fn activate(&mut self, power_up: GamePowerUp) {
+        let timer = Timer::from_seconds(power_up.duration, TimerMode::Once);
+        match self.0.iter_mut().find(|p| p.kind == power_up.kind) {
+            Some(active) => active.timer = timer,
+            None => self.0.push(ActivePowerUp {
+                kind: power_up.kind,
+                timer,
+            }),
+        }
+    }
+
+    pub fn is_active(&self, kind: PowerUpKind) -> bool {
+        self.0.iter().any(|p| p.kind == kind)
+    }
+
+    pub fn points_multiplier(&self) -> i32 {
+        match self.is_active(PowerUpKind::DoublePoints) {
+            true => 2,
+            false => 1,
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &ActivePowerUp> {
+        self.0.iter()
+    }
+
+    pub fn clear(&mut self) {
+        self.0.clear();
+    }
+}
+
+fn tick_power_ups(mut active_power_ups: ResMut<ActivePowerUps>) {
+    if active_power_ups.0.is_empty() {
+        return;
+    }
+
+    for power_up in active_power_ups.0.iter_mut() {
+        power_up
+            .timer
+            .tick(Duration::from_secs_f32(PHYSICS_TIMESTEP));
+    }
+    active_power_ups.0.retain(|p| !p.timer.finished());
+}
+
+fn apply_power_up_effects(
+    mut player: Query<(&mut GravityScale, &mut Restitution), With<Player>>,
+    active_power_ups: Res<ActivePowerUps>,
+) {
+    const LOW_GRAVITY_SCALE: f32 = 0.4;
+
+    let Some((mut gravity_scale, mut restitution)) = player.iter_mut().next() else {
+        return;
+    };
+
+    gravity_scale.set_if_neq(GravityScale(
+        match active_power_ups.is_active(PowerUpKind::LowGravity) {
+            true => LOW_GRAVITY_SCALE,
+            false => 1.0,
+        },
+    ));
+    restitution.set_if_neq(match active_power_ups.is_active(PowerUpKind::Sticky) {
+        true => Restitution {
+            coefficient: 0.0,
+            combine_rule: CoefficientCombineRule::Min,
+        },
+        false => Restitution::new(PLAYER_RESTITUTION),
+    });
+}
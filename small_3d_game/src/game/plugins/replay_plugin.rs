@@ -0,0 +1,323 @@
+use super::{
+    aiming_plugin::{fire_player, ShotFired},
+    game_scene_plugin::{
+        GameData, GameKey, GamePoints, GameSceneAnimationPlayer, Player, PlayerRest, SetGameLevel,
+    },
+    power_up_plugin::GamePowerUp,
+    rewind_plugin::ShotHistory,
+};
+use crate::{
+    game::game_plugin::{GameState, PhysicsSchedule},
+    log,
+    resources::{game_assets::GameAssets, save_data::SaveData},
+    AppState,
+};
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::*;
+use serde_derive::{Deserialize, Serialize};
+
+pub struct ReplayPlugin;
+
+impl Plugin for ReplayPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PhysicsTick>()
+            .init_resource::<ShotRecording>()
+            .init_resource::<ShotReplay>()
+            .add_systems(
+                Update,
+                (
+                    reset_recording,
+                    record_shots
+                        .after(fire_player)
+                        .run_if(in_state(GameState::Playing)),
+                    start_replay_on_key.before(reset_recording),
+                )
+                    .run_if(in_state(AppState::InGame)),
+            )
+            .add_systems(
+                PhysicsSchedule,
+                (
+                    replay_shots
+                        .before(PhysicsSet::SyncBackend)
+                        .run_if(|replay: Res<ShotReplay>| replay.is_active()),
+                    advance_physics_tick
+                        .after(PhysicsSet::Writeback)
+                        .run_if(any_with_component::<Player>),
+                ),
+            )
+            .add_systems(
+                OnEnter(GameState::Finished),
+                // the progress is not saved while the replay is still active
+                finish_replay.after(SaveData::save_system),
+            )
+            .add_systems(OnExit(AppState::InGame), stop_replay);
+    }
+}
+
+/// Physics steps since the player of the current level spawned, leaving the
+/// scene loading out of the recorded ticks. Reset together with the level
+/// entities by `reset_state`.
+#[derive(Resource, Default, Deref, Clone, Copy)]
+pub struct PhysicsTick(u64);
+
+/// Shot fired by the player, applied before the physics step `tick`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ShotRecord {
+    pub tick: u64,
+    /// Id of the level the shot was fired in.
+    pub level: String,
+    pub impulse: [f32; 3],
+    pub torque_impulse: [f32; 3],
+}
+
+impl ShotRecord {
+    fn new(tick: u64, level: &str, impulse: &ExternalImpulse) -> Self {
+        Self {
+            tick,
+            level: level.to_string(),
+            impulse: impulse.impulse.to_array(),
+            torque_impulse: impulse.torque_impulse.to_array(),
+        }
+    }
+
+    fn impulse(&self) -> ExternalImpulse {
+        ExternalImpulse {
+            impulse: Vec3::from_array(self.impulse),
+            torque_impulse: Vec3::from_array(self.torque_impulse),
+        }
+    }
+}
+
+/// Shots of the current run of the level, logged as JSON when it finishes.
+#[derive(Resource, Default, Deref)]
+pub struct ShotRecording(Vec<ShotRecord>);
+
+/// Recorded shots being replayed. Player input and pausing are disabled during
+/// the replay.
+///
+/// Shots are replayed on the recorded physics ticks, while the level animations
+/// and the pulsing collectibles follow the frame time. Replays are only exact on
+/// levels without them.
+#[derive(Resource, Default)]
+pub struct ShotReplay {
+    shots: Vec<ShotRecord>,
+    next: usize,
+    active: bool,
+}
+
+impl ShotReplay {
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    /// Shots fired on the same physics tick would overwrite each other's impulse.
+    fn has_increasing_ticks(shots: &[ShotRecord]) -> bool {
+        shots.windows(2).all(|pair| pair[0].tick < pair[1].tick)
+    }
+
+    /// Next shot due before the physics step `tick`.
+    fn next_shot(&mut self, tick: u64) -> Option<&ShotRecord> {
+        let shot = self.shots.get(self.next).filter(|shot| shot.tick <= tick)?;
+        self.next += 1;
+        Some(shot)
+    }
+}
+
+/// Level objects animated on the frame time. Collectibles change their size with
+/// it, and with it the physics step they are collected on.
+type FrameTimeAnimated = Or<(
+    With<GameSceneAnimationPlayer>,
+    With<GamePoints>,
+    With<GamePowerUp>,
+    With<GameKey>,
+)>;
+
+fn advance_physics_tick(mut tick: ResMut<PhysicsTick>) {
+    tick.0 += 1;
+}
+
+fn reset_recording(
+    mut set_game_level: EventReader<SetGameLevel>,
+    mut recording: ResMut<ShotRecording>,
+    mut replay: ResMut<ShotReplay>,
+) {
+    if set_game_level.read().last().is_none() {
+        return;
+    }
+
+    recording.0.clear();
+    replay.next = 0;
+}
+
+fn record_shots(
+    mut shot_fired: EventReader<ShotFired>,
+    mut recording: ResMut<ShotRecording>,
+    tick: Res<PhysicsTick>,
+    game_data: Res<GameData>,
+    game_assets: Res<GameAssets>,
+) {
+    let Some(level) = game_data.level else {
+        return;
+    };
+    let level = &game_assets.levels.get(level).id;
+
+    // the impulse is applied by the next physics step
+    for ShotFired(impulse) in shot_fired.read() {
+        recording.0.push(ShotRecord::new(**tick, level, impulse));
+    }
+}
+
+fn replay_shots(
+    mut replay: ResMut<ShotReplay>,
+    mut game_data: ResMut<GameData>,
+    mut player: Query<(&mut ExternalImpulse, &mut PlayerRest), With<Player>>,
+    tick: Res<PhysicsTick>,
+) {
+    let Some((mut impulse, mut rest)) = player.iter_mut().next() else {
+        return;
+    };
+
+    while let Some(shot) = replay.next_shot(**tick) {
+        *impulse = shot.impulse();
+        rest.reset();
+        game_data.shots = game_data.shots.saturating_sub(1);
+    }
+}
+
+fn stop_replay(mut replay: ResMut<ShotReplay>) {
+    replay.active = false;
+}
+
+fn finish_replay(
+    mut replay: ResMut<ShotReplay>,
+    recording: Res<ShotRecording>,
+    shot_history: Res<ShotHistory>,
+    animated: Query<(), FrameTimeAnimated>,
+) {
+    if replay.active {
+        replay.active = false;
+        if !animated.is_empty() {
+            log!("Replay: the level animations and collectibles do not replay exactly");
+        }
+        return;
+    }
+    if recording.is_empty() {
+        return;
+    }
+
+    if shot_history.rewinds() > 0 {
+        log!("Replay: the run was rewound and does not replay exactly");
+    }
+    if !animated.is_empty() {
+        log!("Replay: the level animations and collectibles do not replay exactly");
+    }
+    match serde_json::to_string(&recording.0) {
+        Ok(json) => log!("Replay: {}", json),
+        Err(e) => log!("Replay: failed to serialize: {}", e),
+    }
+}
+
+/// F8 replays the last run of the level, F9 the shots stored in `replay.json`.
+fn start_replay_on_key(
+    key_input: Res<ButtonInput<KeyCode>>,
+    recording: Res<ShotRecording>,
+    mut replay: ResMut<ShotReplay>,
+    mut set_game_level: EventWriter<SetGameLevel>,
+    mut next_state: ResMut<NextState<GameState>>,
+    game_assets: Res<GameAssets>,
+) {
+    let shots = if key_input.just_pressed(KeyCode::F8) {
+        recording.0.clone()
+    } else if key_input.just_pressed(KeyCode::F9) {
+        match load_replay() {
+            Ok(shots) => shots,
+            Err(e) => {
+                log!("Replay: failed to load: {}", e);
+                return;
+            }
+        }
+    } else {
+        return;
+    };
+
+    let Some(first) = shots.first() else {
+        log!("Replay: no shots to replay");
+        return;
+    };
+    let Some(level) = game_assets.levels.find(&first.level) else {
+        log!("Replay: unknown level: {}", first.level);
+        return;
+    };
+    if !ShotReplay::has_increasing_ticks(&shots) {
+        log!("Replay: shots must be fired on increasing ticks");
+        return;
+    }
+
+    *replay = ShotReplay {
+        shots,
+        next: 0,
+        active: true,
+    };
+    next_state.set(GameState::Playing);
+    set_game_level.send(SetGameLevel(Some(level)));
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn load_replay() -> Result<Vec<ShotRecord>, Box<dyn std::error::Error>> {
+    let json = std::fs::read_to_string("replay.json")?;
+    Ok(serde_json::from_str(&json)?)
+}
+
+#[cfg(target_arch = "wasm32")]
+fn load_replay() -> Result<Vec<ShotRecord>, Box<dyn std::error::Error>> {
+    Err("loading replays is not supported on the web".into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shot_record_json() {
+        let impulse = ExternalImpulse {
+            impulse: Vec3::new(1.0, -2.5, 0.0),
+            torque_impulse: Vec3::new(0.0, 0.0, 0.25),
+        };
+        let shot = ShotRecord::new(120, "demo", &impulse);
+        assert_eq!(shot.impulse(), impulse);
+
+        let json = serde_json::to_string(&vec![shot.clone()]).unwrap();
+        assert_eq!(
+            serde_json::from_str::<Vec<ShotRecord>>(&json).unwrap(),
+            vec![shot]
+        );
+    }
+
+    #[test]
+    fn test_replay_shot_tick() {
+        // `record_shots` runs after the physics step advancing the tick to 120,
+        // the impulse is applied by the following step run with the tick 120
+        let impulse = ExternalImpulse {
+            impulse: Vec3::X,
+            torque_impulse: Vec3::ZERO,
+        };
+        let mut replay = ShotReplay {
+            shots: vec![
+                ShotRecord::new(120, "demo", &impulse),
+                ShotRecord::new(300, "demo", &impulse),
+            ],
+            next: 0,
+            active: true,
+        };
+        assert!(ShotReplay::has_increasing_ticks(&replay.shots));
+
+        assert_eq!(replay.next_shot(119), None);
+        assert_eq!(replay.next_shot(120).map(|shot| shot.tick), Some(120));
+        assert_eq!(replay.next_shot(120), None);
+        assert_eq!(replay.next_shot(300).map(|shot| shot.tick), Some(300));
+        assert_eq!(replay.next_shot(301), None);
+
+        let shot = ShotRecord::new(120, "demo", &impulse);
+        assert!(!ShotReplay::has_increasing_ticks(&[shot.clone(), shot]));
+    }
+}
@@ -0,0 +1,236 @@
+use super::{
+    aiming_plugin::{fire_player, DragInfo, ShotFired},
+    breakable_plugin::RecentDebris,
+    game_scene_plugin::{
+        Collected, GameData, GameDoor, GameInventory, GameSceneAnimationPlayer, OpenedDoor, Player,
+        PlayerRest,
+    },
+    power_up_plugin::ActivePowerUps,
+    replay_plugin::ShotReplay,
+};
+use crate::{
+    game::game_plugin::GameState,
+    resources::game_assets::{GameAssets, GameLevelMeta},
+    AppState,
+};
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::*;
+
+pub struct RewindPlugin;
+
+impl Plugin for RewindPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PracticeMode>()
+            .init_resource::<ShotHistory>()
+            .add_event::<RewindShot>()
+            .add_systems(
+                Update,
+                (
+                    take_shot_snapshot.after(fire_player),
+                    rewind_on_key,
+                    rewind_shot.after(rewind_on_key),
+                )
+                    .run_if(in_state(AppState::InGame))
+                    .run_if(in_state(GameState::Playing)),
+            );
+    }
+}
+
+/// Practice runs allow unlimited rewinds and do not save the level progress.
+#[derive(Resource, Default, Deref, DerefMut, Clone, Copy)]
+pub struct PracticeMode(pub bool);
+
+impl PracticeMode {
+    /// Rewinds allowed per run of the level, unlimited without a limit.
+    pub fn rewind_limit(&self, meta: &GameLevelMeta) -> Option<u32> {
+        match self.0 {
+            true => None,
+            false => Some(meta.rewinds.unwrap_or_default()),
+        }
+    }
+}
+
+/// Restores the level to the state before the last shot.
+#[derive(Event)]
+pub struct RewindShot;
+
+/// Level state before the last shot and the rewinds used in the current run.
+#[derive(Resource, Default)]
+pub struct ShotHistory {
+    snapshot: Option<ShotSnapshot>,
+    rewinds: u32,
+}
+
+impl ShotHistory {
+    pub fn can_rewind(&self, limit: Option<u32>) -> bool {
+        self.snapshot.is_some() && limit.is_none_or(|limit| self.rewinds < limit)
+    }
+
+    pub fn rewinds(&self) -> u32 {
+        self.rewinds
+    }
+}
+
+struct ShotSnapshot {
+    transform: Transform,
+    velocity: Velocity,
+    shots: u32,
+    points: i32,
+    inventory: GameInventory,
+    power_ups: ActivePowerUps,
+    animations: Vec<(Entity, AnimationSnapshot)>,
+}
+
+struct AnimationSnapshot {
+    seek_time: f32,
+    speed: f32,
+    paused: bool,
+}
+
+#[allow(clippy::too_many_arguments)]
+fn take_shot_snapshot(
+    mut commands: Commands,
+    mut shot_fired: EventReader<ShotFired>,
+    mut shot_history: ResMut<ShotHistory>,
+    game_data: Res<GameData>,
+    inventory: Res<GameInventory>,
+    active_power_ups: Res<ActivePowerUps>,
+    player: Query<(&Transform, &Velocity), With<Player>>,
+    animation_players: Query<(Entity, &AnimationPlayer), With<GameSceneAnimationPlayer>>,
+    collected: Query<Entity, With<Collected>>,
+    debris: Query<Entity, With<RecentDebris>>,
+) {
+    if shot_fired.read().last().is_none() {
+        return;
+    }
+    let Some((transform, velocity)) = player.iter().next() else {
+        return;
+    };
+
+    // objects collected before this shot can not be rewound anymore
+    for entity in collected.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+    for entity in debris.iter() {
+        commands.entity(entity).remove::<RecentDebris>();
+    }
+
+    shot_history.snapshot = Some(ShotSnapshot {
+        transform: *transform,
+        velocity: *velocity,
+        // the shot was already taken by `fire_player`
+        shots: game_data.shots + 1,
+        points: game_data.points,
+        inventory: inventory.clone(),
+        power_ups: active_power_ups.clone(),
+        animations: animation_players
+            .iter()
+            .map(|(entity, animation_player)| {
+                let snapshot = AnimationSnapshot {
+                    seek_time: animation_player.seek_time(),
+                    speed: animation_player.speed(),
+                    paused: animation_player.is_paused(),
+                };
+                (entity, snapshot)
+            })
+            .collect(),
+    });
+}
+
+fn rewind_on_key(key_input: Res<ButtonInput<KeyCode>>, mut rewind: EventWriter<RewindShot>) {
+    if key_input.just_pressed(KeyCode::KeyZ) {
+        rewind.send(RewindShot);
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn rewind_shot(
+    mut commands: Commands,
+    mut rewind: EventReader<RewindShot>,
+    mut shot_history: ResMut<ShotHistory>,
+    mut game_data: ResMut<GameData>,
+    mut inventory: ResMut<GameInventory>,
+    mut active_power_ups: ResMut<ActivePowerUps>,
+    mut drag_info: ResMut<DragInfo>,
+    practice: Res<PracticeMode>,
+    replay: Res<ShotReplay>,
+    game_assets: Res<GameAssets>,
+    mut player: Query<
+        (
+            &mut Transform,
+            &mut Velocity,
+            &mut ExternalImpulse,
+            &mut PlayerRest,
+        ),
+        With<Player>,
+    >,
+    mut animation_players: Query<&mut AnimationPlayer, With<GameSceneAnimationPlayer>>,
+    collected: Query<Entity, With<Collected>>,
+    opened_doors: Query<(Entity, &GameDoor), With<OpenedDoor>>,
+    debris: Query<Entity, With<RecentDebris>>,
+    children: Query<&Children>,
+) {
+    // replays only reproduce the recorded shots
+    if rewind.read().last().is_none() || replay.is_active() {
+        return;
+    }
+    let Some(level) = game_data.level else {
+        return;
+    };
+    if !shot_history.can_rewind(practice.rewind_limit(game_assets.levels.get(level))) {
+        return;
+    }
+    let Some((mut transform, mut velocity, mut impulse, mut rest)) = player.iter_mut().next()
+    else {
+        return;
+    };
+    let Some(snapshot) = shot_history.snapshot.take() else {
+        return;
+    };
+    shot_history.rewinds += 1;
+
+    *transform = snapshot.transform;
+    *velocity = snapshot.velocity;
+    *impulse = ExternalImpulse::default();
+    rest.reset();
+    **drag_info = None;
+
+    for (entity, animation) in snapshot.animations {
+        let Ok(mut animation_player) = animation_players.get_mut(entity) else {
+            continue;
+        };
+        animation_player
+            .seek_to(animation.seek_time)
+            .set_speed(animation.speed);
+        match animation.paused {
+            true => animation_player.pause(),
+            false => animation_player.resume(),
+        }
+    }
+
+    for entity in collected.iter() {
+        commands
+            .entity(entity)
+            .remove::<Collected>()
+            .try_insert(Visibility::Inherited);
+        for child in children.iter_descendants(entity) {
+            commands.entity(child).remove::<ColliderDisabled>();
+        }
+    }
+
+    for entity in debris.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+
+    for (entity, door) in opened_doors.iter() {
+        if !snapshot.inventory.has_key(door.color) {
+            commands.entity(entity).remove::<OpenedDoor>();
+        }
+    }
+
+    game_data.shots = snapshot.shots;
+    game_data.points = snapshot.points;
+    game_data.result = None;
+    *inventory = snapshot.inventory;
+    *active_power_ups = snapshot.power_ups;
+}
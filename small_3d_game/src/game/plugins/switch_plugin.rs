@@ -0,0 +1,122 @@
+use super::game_scene_plugin::{player_collision_started, GameSceneAnimationPlayer, Player};
+use crate::{
+    game::{game_plugin::GameState, level_object::strip_duplicate_suffix},
+    AppState,
+};
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::*;
+use serde_derive::Deserialize;
+
+pub struct SwitchPlugin;
+
+impl Plugin for SwitchPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            trigger_switch_on_collision
+                .run_if(in_state(AppState::InGame))
+                .run_if(in_state(GameState::Playing)),
+        );
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize)]
+pub enum SwitchAction {
+    Start,
+    Stop,
+    Reverse,
+    #[default]
+    Toggle,
+}
+
+/// Sensor controlling the animation of the level objects named `target`, or of
+/// its duplicates when no object has exactly that name.
+#[derive(Component, Clone, Debug, PartialEq, Eq)]
+pub struct GameSwitch {
+    pub target: String,
+    pub action: SwitchAction,
+}
+
+impl GameSwitch {
+    fn is_target(&self, name: &str, exact: bool) -> bool {
+        match exact {
+            true => name == self.target,
+            false => strip_duplicate_suffix(name) == strip_duplicate_suffix(&self.target),
+        }
+    }
+
+    fn apply(&self, animation_player: &mut AnimationPlayer) {
+        match self.action {
+            SwitchAction::Start => {
+                if animation_player.is_finished() {
+                    animation_player.replay();
+                }
+                animation_player.resume();
+            }
+            SwitchAction::Stop => animation_player.pause(),
+            SwitchAction::Reverse => {
+                let speed = animation_player.speed();
+                animation_player.set_speed(-speed);
+            }
+            SwitchAction::Toggle => match animation_player.is_paused() {
+                true => animation_player.resume(),
+                false => animation_player.pause(),
+            },
+        }
+    }
+}
+
+fn trigger_switch_on_collision(
+    mut collision_events: EventReader<CollisionEvent>,
+    player: Query<Entity, With<Player>>,
+    switches: Query<&GameSwitch>,
+    mut animation_players: Query<(&Name, &mut AnimationPlayer), With<GameSceneAnimationPlayer>>,
+) {
+    let Some(player_entity) = player.iter().next() else {
+        return;
+    };
+
+    for collision_event in collision_events.read() {
+        let Some((_, switch_entity)) = player_collision_started(collision_event, player_entity)
+        else {
+            continue;
+        };
+        let Ok(switch) = switches.get(switch_entity) else {
+            continue;
+        };
+
+        let exact = animation_players
+            .iter()
+            .any(|(name, _)| switch.is_target(name, true));
+        for (_, mut animation_player) in animation_players
+            .iter_mut()
+            .filter(|(name, _)| switch.is_target(name, exact))
+        {
+            switch.apply(&mut animation_player);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_target() {
+        let switch = GameSwitch {
+            target: "Object.028".to_string(),
+            action: SwitchAction::Toggle,
+        };
+        assert!(switch.is_target("Object.028", true));
+        assert!(!switch.is_target("Object.027", true));
+        assert!(switch.is_target("Object.027", false));
+        assert!(switch.is_target("Object", false));
+
+        let switch = GameSwitch {
+            target: "Platform".to_string(),
+            action: SwitchAction::Toggle,
+        };
+        assert!(switch.is_target("Platform.001", false));
+        assert!(!switch.is_target("Platform_Base", false));
+    }
+}
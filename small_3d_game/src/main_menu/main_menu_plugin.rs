@@ -5,8 +5,10 @@ use crate::common::plugins::ui_plugin::components::{
 use crate::common::plugins::ui_plugin::{UiCommandContext, UiOnClick, UiPointerEventData};
 use crate::game::game_plugin::GameState;
 use crate::game::plugins::game_scene_plugin::GameData;
+use crate::game::plugins::rewind_plugin::PracticeMode;
 use crate::resources::game_assets::{GameAssets, GameColor, GameLevel};
 use crate::resources::loadable::Loadable;
+use crate::resources::save_data::SaveData;
 use crate::resources::text_styles::{FontSize, FontType};
 use crate::{AppState, TextStyles};
 use bevy::prelude::*;
@@ -67,6 +69,9 @@ struct MenuNode;
 #[derive(Component)]
 struct MenuContainer;
 
+#[derive(Component, Clone, Copy)]
+struct LevelSelectButton(GameLevel);
+
 fn init_main_menu(mut commands: Commands, mut ui_builder: UiBuilder, mut state: ResMut<MenuState>) {
     // TODO: Background scene'
     let base = UiBase::new(ui_builder.game_assets.colors.get(GameColor::Base));
@@ -94,6 +99,8 @@ fn update_menu(
     mut commands: Commands,
     state: Res<MenuState>,
     container: Query<Entity, With<MenuContainer>>,
+    save_data: Res<SaveData>,
+    practice: Res<PracticeMode>,
     mut ui_builder: UiBuilder,
 ) {
     let Some(container) = container.iter().next() else {
@@ -108,9 +115,13 @@ fn update_menu(
         }
         MenuState::Play(play_node) => match play_node {
             PlayMenuState::Root => spawn_play_root(&mut commands, &mut ui_builder, container),
-            PlayMenuState::LevelSelect => {
-                spawn_level_select(&mut commands, &mut ui_builder, container)
-            }
+            PlayMenuState::LevelSelect => spawn_level_select(
+                &mut commands,
+                &mut ui_builder,
+                &save_data,
+                *practice,
+                container,
+            ),
             PlayMenuState::Customize => {}
             PlayMenuState::Achievements => {}
         },
@@ -135,7 +146,13 @@ fn update_menu(
     });
 }
 
-fn spawn_level_select(commands: &mut Commands, ui_builder: &mut UiBuilder, container: Entity) {
+fn spawn_level_select(
+    commands: &mut Commands,
+    ui_builder: &mut UiBuilder,
+    save_data: &SaveData,
+    practice: PracticeMode,
+    container: Entity,
+) {
     let title = ui_builder
         .create_auto::<UiText>()
         .with_text("Level select")
@@ -149,18 +166,61 @@ fn spawn_level_select(commands: &mut Commands, ui_builder: &mut UiBuilder, conta
                     .get_content(GameColor::Primary),
             ),
         );
-    let buttons = vec![ui_builder
+    let practice_button = ui_builder
         .create::<UiButton>(Val::Auto, Val::Auto)
-        .with_text("Demo")
-        .with_on_click(UiOnClick::new(|w, ctx| {
-            w.resource_mut::<GameData>().level = Some(GameLevel::Demo);
-            set_in_game(w, ctx);
-        }))];
+        .with_text(match *practice {
+            true => "Practice: on",
+            false => "Practice: off",
+        })
+        .with_on_click(UiOnClick::new(|w, _| {
+            let mut practice = w.resource_mut::<PracticeMode>();
+            **practice = !**practice;
+            w.resource_mut::<MenuState>().set_changed();
+        }))
+        .with_game_color(GameColor::Neutral, ui_builder);
+    let buttons = ui_builder
+        .game_assets
+        .levels
+        .iter()
+        .map(|(level, meta)| {
+            let unlocked = save_data.is_unlocked(&ui_builder.game_assets.levels, level);
+            (level, meta.name.clone(), unlocked)
+        })
+        .collect::<Vec<_>>()
+        .into_iter()
+        .map(|(level, name, unlocked)| {
+            let button = ui_builder.create::<UiButton>(Val::Auto, Val::Auto);
+            if !unlocked {
+                return (
+                    button
+                        .with_text(format!("{} (locked)", name))
+                        .with_game_color(GameColor::Neutral, ui_builder),
+                    None,
+                );
+            }
+            let button = button
+                .with_text(name)
+                .with_on_click(UiOnClick::new(|w, ctx| {
+                    let Some(LevelSelectButton(level)) =
+                        w.get::<LevelSelectButton>(ctx.entity).copied()
+                    else {
+                        return;
+                    };
+                    w.resource_mut::<GameData>().level = Some(level);
+                    set_in_game(w, ctx);
+                }));
+            (button, Some(LevelSelectButton(level)))
+        })
+        .collect::<Vec<_>>();
 
     commands.entity(container).with_children(|parent| {
         title.spawn(parent);
-        for button in buttons.into_iter() {
-            button.spawn(parent);
+        practice_button.spawn(parent);
+        for (button, level) in buttons.into_iter() {
+            let mut button = button.spawn(parent);
+            if let Some(level) = level {
+                button.insert(level);
+            }
         }
     });
 }
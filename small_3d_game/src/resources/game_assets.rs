@@ -2,7 +2,7 @@ use super::loadable::Loadable;
 use crate::{
     game::plugins::{
         aiming_plugin::ArrowAnimationPlayer,
-        game_scene_plugin::{GameData, GameSceneAnimationPlayer},
+        game_scene_plugin::{GameData, GameDoor, GameSceneAnimationPlayer, PausedAnimation},
     },
     log,
 };
@@ -66,40 +66,130 @@ impl GameImage {
 }
 
 #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
-pub enum GameLevel {
-    Demo,
-}
+pub struct GameLevel(usize);
 
+#[derive(Clone, Debug, Reflect, Serialize, Deserialize)]
 pub struct GameLevelMeta {
+    pub id: String,
+    pub name: String,
+    pub path: String,
     pub shots: u32,
     pub star_point_thresholds: [u32; 3],
+    /// Leaving the bounds respawns the player instead of losing the level.
+    pub respawn: Option<RespawnPenalty>,
+    /// Maximal length of the aiming trajectory preview, no preview without it.
+    pub trajectory_preview: Option<f32>,
+    /// Shot rewinds allowed per run outside of the practice mode.
+    pub rewinds: Option<u32>,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Reflect, Serialize, Deserialize)]
+pub struct RespawnPenalty {
+    #[serde(default)]
+    pub shots: u32,
+    #[serde(default)]
+    pub points: i32,
+}
+
+impl GameLevelMeta {
+    pub fn get_stars(&self, points: i32) -> u8 {
+        self.star_point_thresholds
+            .into_iter()
+            .position(|threshold| points < threshold as i32)
+            .unwrap_or(3) as u8
+    }
+}
+
+impl GameAnimationSource for GameLevelMeta {
+    fn get_animation_filename(&self) -> &str {
+        &self.path
+    }
 }
 
-impl From<GameLevel> for GameData {
-    fn from(val: GameLevel) -> Self {
-        let meta = val.get_meta();
+#[derive(Clone, Debug, Reflect, Default)]
+pub struct GameLevels {
+    data: Vec<GameLevelMeta>,
+}
+
+impl GameLevels {
+    pub fn from_json(json: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        let data = serde_json::from_str::<Vec<GameLevelMeta>>(json)?;
+        if data.is_empty() {
+            return Err("Empty levels!".into());
+        }
+        for (i, meta) in data.iter().enumerate() {
+            if data[..i].iter().any(|m| m.id == meta.id) {
+                return Err(format!("Duplicate level id: {}", meta.id).into());
+            }
+        }
+
+        let result = Self { data };
+        log!("GameLevels: {:?}", result);
+
+        Ok(result)
+    }
+
+    pub fn get(&self, level: GameLevel) -> &GameLevelMeta {
+        &self.data[level.0]
+    }
+
+    pub fn find(&self, id: &str) -> Option<GameLevel> {
+        self.data.iter().position(|m| m.id == id).map(GameLevel)
+    }
+
+    pub fn next(&self, level: GameLevel) -> Option<GameLevel> {
+        Some(GameLevel(level.0 + 1)).filter(|next| next.0 < self.data.len())
+    }
+
+    pub fn previous(&self, level: GameLevel) -> Option<GameLevel> {
+        level.0.checked_sub(1).map(GameLevel)
+    }
+
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = (GameLevel, &GameLevelMeta)> {
+        self.data.iter().enumerate().map(|(i, m)| (GameLevel(i), m))
+    }
+
+    pub fn get_game_data(&self, level: GameLevel) -> GameData {
         GameData {
-            level: Some(val),
-            shots: meta.shots,
+            level: Some(level),
+            shots: self.get(level).shots,
             ..Default::default()
         }
     }
 }
 
-impl GameLevel {
-    pub fn get_filename(&self) -> &str {
-        match self {
-            Self::Demo => "models/levels/demo.glb",
+/// Physical properties of a level surface type, e.g. ice or sand.
+#[derive(Clone, Copy, Debug, PartialEq, Reflect, Serialize, Deserialize)]
+pub struct GameSurface {
+    pub friction: f32,
+    pub restitution: f32,
+    /// Additional player damping while rolling on the surface.
+    pub rolling_damping: f32,
+}
+
+#[derive(Clone, Debug, Reflect, Default)]
+pub struct GameSurfaces {
+    data: HashMap<String, GameSurface>,
+}
+
+impl GameSurfaces {
+    pub fn from_json(json: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        let data = serde_json::from_str::<HashMap<String, GameSurface>>(json)?;
+        if let Some((name, _)) = data
+            .iter()
+            .find(|(_, s)| s.friction < 0.0 || s.restitution < 0.0 || s.rolling_damping < 0.0)
+        {
+            return Err(format!("Negative surface property: {}", name).into());
         }
+
+        let result = Self { data };
+        log!("GameSurfaces: {:?}", result);
+
+        Ok(result)
     }
 
-    pub fn get_meta(&self) -> GameLevelMeta {
-        match self {
-            Self::Demo => GameLevelMeta {
-                shots: 3,
-                star_point_thresholds: [25, 50, 75],
-            },
-        }
+    pub fn get(&self, name: &str) -> Option<&GameSurface> {
+        self.data.get(name)
     }
 }
 
@@ -162,12 +252,23 @@ impl GameColors {
 #[derive(Resource, Clone, Reflect)]
 pub struct GameAssets {
     pub colors: GameColors,
+    pub levels: GameLevels,
+    pub surfaces: GameSurfaces,
     scenes: HashMap<GameScene, Handle<Scene>>,
     animations: HashMap<String, HashMap<Name, Handle<AnimationClip>>>,
     materials: HashMap<GameMaterial, Handle<StandardMaterial>>,
     images: HashMap<GameImage, Handle<Image>>,
 }
 
+type GameSceneAnimation<'a> = (
+    Entity,
+    &'a Name,
+    &'a mut AnimationPlayer,
+    &'a GameSceneAnimationPlayer,
+    Has<PausedAnimation>,
+    Has<GameDoor>,
+);
+
 impl GameAssets {
     pub fn get_scene(&self, asset: GameScene) -> Handle<Scene> {
         self.scenes[&asset].clone_weak()
@@ -220,13 +321,9 @@ impl GameAssets {
                 Without<GameSceneAnimationPlayer>,
             ),
         >,
-        mut new_game_scene_animations: Query<
-            (Entity, &Name, &mut AnimationPlayer),
-            Added<GameSceneAnimationPlayer>,
-        >,
+        mut new_game_scene_animations: Query<GameSceneAnimation, Added<GameSceneAnimationPlayer>>,
         mut game_assets: ResMut<GameAssets>,
         asset_server: Res<AssetServer>,
-        game_data: Res<GameData>,
         mut material_queue: Local<HashMap<Handle<StandardMaterial>, u64>>,
     ) {
         for handle in new_base_materials.iter() {
@@ -263,13 +360,18 @@ impl GameAssets {
             player.play(game_assets.get_next_animation(name, &ArrowAnimationPlayer, &asset_server));
             player.pause();
         }
-        for (entity, name, mut player) in new_game_scene_animations.iter_mut() {
-            player.play(game_assets.get_next_animation(
-                name,
-                &GameSceneAnimationPlayer(game_data.level.unwrap()),
-                &asset_server,
-            ));
-            player.repeat();
+        for (entity, name, mut player, scene_player, paused, door) in
+            new_game_scene_animations.iter_mut()
+        {
+            let meta = game_assets.levels.get(scene_player.0).clone();
+            player.play(game_assets.get_next_animation(name, &meta, &asset_server));
+            // doors play their animation once, switched animations loop once started
+            if !door {
+                player.repeat();
+            }
+            if paused {
+                player.pause();
+            }
             commands.entity(entity).insert((
                 Ccd::enabled(),
                 RigidBody::KinematicPositionBased,
@@ -308,6 +410,8 @@ impl IntoIterator for &GameAssets {
 }
 
 const THEMES_JSON: &str = include_str!("../../assets/themes.json");
+const LEVELS_JSON: &str = include_str!("../../assets/levels.json");
+const SURFACES_JSON: &str = include_str!("../../assets/surfaces.json");
 
 impl FromWorld for GameAssets {
     fn from_world(world: &mut World) -> Self {
@@ -325,10 +429,13 @@ impl FromWorld for GameAssets {
             GameScene::AimArrow,
             asset_server.load("models/arrow.glb#Scene0"),
         );
-        scenes.insert(
-            GameScene::Level(GameLevel::Demo),
-            asset_server.load(format!("{}#Scene0", GameLevel::Demo.get_filename())),
-        );
+        let levels = GameLevels::from_json(LEVELS_JSON).unwrap();
+        for (level, meta) in levels.iter() {
+            scenes.insert(
+                GameScene::Level(level),
+                asset_server.load(format!("{}#Scene0", meta.path)),
+            );
+        }
         let mut materials = HashMap::default();
         materials.insert(
             GameMaterial::AimArrowBody,
@@ -345,12 +452,77 @@ impl FromWorld for GameAssets {
         images.insert(GameImage::Player, asset_server.load("images/player.png"));
         images.insert(GameImage::Star, asset_server.load("images/star.png"));
         let colors = GameColors::from_json(THEMES_JSON).unwrap();
+        let surfaces = GameSurfaces::from_json(SURFACES_JSON).unwrap();
         Self {
             scenes,
             animations: Default::default(),
             materials,
             images,
             colors,
+            levels,
+            surfaces,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_levels_from_json() {
+        let levels = GameLevels::from_json(LEVELS_JSON).unwrap();
+        let (demo, meta) = levels.iter().next().unwrap();
+        assert_eq!(levels.find("demo"), Some(demo));
+        assert_eq!(meta.path, "models/levels/demo.glb");
+        assert_eq!(levels.get_game_data(demo).shots, meta.shots);
+        assert_eq!(levels.previous(demo), None);
+
+        assert!(GameLevels::from_json("[]").is_err());
+        let level = r#"{
+            "id": "a",
+            "name": "A",
+            "path": "a.glb",
+            "shots": 1,
+            "star_point_thresholds": [1, 2, 3]
+        }"#;
+        assert!(GameLevels::from_json(&format!("[{0}, {0}]", level)).is_err());
+        let levels =
+            GameLevels::from_json(&format!("[{}, {}]", level, level.replace("\"a\"", "\"b\"")))
+                .unwrap();
+        let a = levels.find("a").unwrap();
+        let b = levels.find("b").unwrap();
+        assert_eq!(levels.next(a), Some(b));
+        assert_eq!(levels.next(b), None);
+        assert_eq!(levels.previous(b), Some(a));
+        assert_eq!(levels.get(a).respawn, None);
+
+        let levels = GameLevels::from_json(&format!(
+            "[{}]",
+            level.replace(r#""shots": 1,"#, r#""shots": 1, "respawn": {"shots": 1},"#)
+        ))
+        .unwrap();
+        assert_eq!(
+            levels.get(levels.find("a").unwrap()).respawn,
+            Some(RespawnPenalty {
+                shots: 1,
+                points: 0
+            })
+        );
+    }
+
+    #[test]
+    fn test_surfaces_from_json() {
+        let surfaces = GameSurfaces::from_json(SURFACES_JSON).unwrap();
+        let ice = surfaces.get("ice").unwrap();
+        let sand = surfaces.get("sand").unwrap();
+        assert!(ice.friction < sand.friction);
+        assert!(ice.rolling_damping < sand.rolling_damping);
+        assert_eq!(surfaces.get("lava"), None);
+
+        assert!(GameSurfaces::from_json(
+            r#"{"mud": {"friction": -1, "restitution": 0, "rolling_damping": 0}}"#
+        )
+        .is_err());
+    }
+}
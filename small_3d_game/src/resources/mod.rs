@@ -3,4 +3,5 @@ pub mod inputs;
 pub mod loadable;
 pub mod random;
 pub mod resources_plugin;
+pub mod save_data;
 pub mod text_styles;
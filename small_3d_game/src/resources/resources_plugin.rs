@@ -1,4 +1,10 @@
-use super::{game_assets::GameAssets, random::Random, text_styles::TextStyles};
+use super::{
+    game_assets::GameAssets,
+    random::Random,
+    save_data::{SaveData, SaveDataStorage},
+    text_styles::TextStyles,
+};
+use crate::{game::game_plugin::GameState, AppState};
 use bevy::prelude::*;
 
 pub struct ResourcesPlugin;
@@ -7,7 +13,11 @@ impl Plugin for ResourcesPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<TextStyles>()
             .init_resource::<GameAssets>()
+            .init_resource::<SaveData>()
+            .init_resource::<SaveDataStorage>()
             .init_non_send_resource::<Random>()
-            .add_systems(Update, GameAssets::init_assets_system);
+            .add_systems(Update, GameAssets::init_assets_system)
+            .add_systems(OnEnter(AppState::Loading), SaveData::load_system)
+            .add_systems(OnEnter(GameState::Finished), SaveData::save_system);
     }
 }
@@ -0,0 +1,182 @@
+use super::game_assets::{GameAssets, GameLevel, GameLevels};
+use crate::{
+    game::plugins::{
+        game_scene_plugin::GameData, replay_plugin::ShotReplay, rewind_plugin::PracticeMode,
+    },
+    log,
+};
+use bevy::{prelude::*, utils::HashMap};
+use serde_derive::{Deserialize, Serialize};
+use std::error::Error;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LevelProgress {
+    pub best_points: i32,
+    pub best_stars: u8,
+    pub completed: bool,
+}
+
+#[derive(Resource, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SaveData {
+    levels: HashMap<String, LevelProgress>,
+}
+
+impl SaveData {
+    pub fn get(&self, id: &str) -> Option<&LevelProgress> {
+        self.levels.get(id)
+    }
+
+    pub fn is_unlocked(&self, levels: &GameLevels, level: GameLevel) -> bool {
+        levels.previous(level).is_none_or(|previous| {
+            self.get(&levels.get(previous).id)
+                .is_some_and(|progress| progress.completed)
+        })
+    }
+
+    pub fn complete_level(&mut self, id: &str, points: i32, stars: u8) {
+        let progress = self.levels.entry(id.to_string()).or_insert(LevelProgress {
+            best_points: points,
+            ..Default::default()
+        });
+        progress.best_points = progress.best_points.max(points);
+        progress.best_stars = progress.best_stars.max(stars);
+        progress.completed = true;
+    }
+
+    pub fn load_system(mut save_data: ResMut<SaveData>, storage: Res<SaveDataStorage>) {
+        match storage.load() {
+            Ok(Some(data)) => *save_data = data,
+            Ok(None) => log!("SaveData: nothing saved yet"),
+            Err(e) => log!("SaveData: failed to load: {}", e),
+        }
+    }
+
+    pub fn save_system(
+        mut save_data: ResMut<SaveData>,
+        storage: Res<SaveDataStorage>,
+        game_data: Res<GameData>,
+        game_assets: Res<GameAssets>,
+        practice: Res<PracticeMode>,
+        replay: Res<ShotReplay>,
+    ) {
+        let Some(level) = game_data.level else {
+            return;
+        };
+        if **practice || replay.is_active() {
+            return;
+        }
+        if game_data.result == Some(true) {
+            let meta = game_assets.levels.get(level);
+            save_data.complete_level(&meta.id, game_data.points, meta.get_stars(game_data.points));
+        }
+        if let Err(e) = storage.store(&save_data) {
+            log!("SaveData: failed to store: {}", e);
+        }
+    }
+}
+
+pub trait SaveStorage: Send + Sync {
+    fn load(&self) -> Result<Option<SaveData>, Box<dyn Error>>;
+    fn store(&self, data: &SaveData) -> Result<(), Box<dyn Error>>;
+}
+
+#[derive(Resource, Deref)]
+pub struct SaveDataStorage(Box<dyn SaveStorage>);
+
+impl Default for SaveDataStorage {
+    fn default() -> Self {
+        #[cfg(target_arch = "wasm32")]
+        return Self(Box::new(BrowserStorage));
+        #[cfg(not(target_arch = "wasm32"))]
+        return Self(Box::new(FileStorage::default()));
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub struct FileStorage {
+    path: std::path::PathBuf,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Default for FileStorage {
+    fn default() -> Self {
+        const FILENAME: &str = "save_data.json";
+        #[cfg(target_os = "android")]
+        let dir = bevy::winit::ANDROID_APP
+            .get()
+            .and_then(|app| app.internal_data_path())
+            .unwrap_or_default();
+        #[cfg(not(target_os = "android"))]
+        let dir = std::path::PathBuf::default();
+        Self {
+            path: dir.join(FILENAME),
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl SaveStorage for FileStorage {
+    fn load(&self) -> Result<Option<SaveData>, Box<dyn Error>> {
+        match std::fs::read_to_string(&self.path) {
+            Ok(json) => Ok(Some(serde_json::from_str(&json)?)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    fn store(&self, data: &SaveData) -> Result<(), Box<dyn Error>> {
+        std::fs::write(&self.path, serde_json::to_string(data)?)?;
+        Ok(())
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+pub struct BrowserStorage;
+
+#[cfg(target_arch = "wasm32")]
+impl BrowserStorage {
+    const KEY: &'static str = "small_3d_save_data";
+}
+
+#[cfg(target_arch = "wasm32")]
+impl SaveStorage for BrowserStorage {
+    fn load(&self) -> Result<Option<SaveData>, Box<dyn Error>> {
+        use gloo::storage::{errors::StorageError, LocalStorage, Storage};
+        match LocalStorage::get(Self::KEY) {
+            Ok(data) => Ok(Some(data)),
+            Err(StorageError::KeyNotFound(_)) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    fn store(&self, data: &SaveData) -> Result<(), Box<dyn Error>> {
+        use gloo::storage::{LocalStorage, Storage};
+        LocalStorage::set(Self::KEY, data)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_complete_level_keeps_best() {
+        let mut save_data = SaveData::default();
+        assert_eq!(save_data.get("demo"), None);
+
+        save_data.complete_level("demo", 60, 2);
+        save_data.complete_level("demo", 30, 1);
+        assert_eq!(
+            save_data.get("demo"),
+            Some(&LevelProgress {
+                best_points: 60,
+                best_stars: 2,
+                completed: true,
+            })
+        );
+
+        let json = serde_json::to_string(&save_data).unwrap();
+        assert_eq!(serde_json::from_str::<SaveData>(&json).unwrap(), save_data);
+    }
+}